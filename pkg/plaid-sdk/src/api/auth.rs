@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::{
+    model::auth::{AuthGetRequest, AuthGetResponse},
+    traits::{AuthApi, Endpoint},
+    PlaidClient, PlaidResult,
+};
+
+impl Endpoint for AuthGetRequest {
+    type Response = AuthGetResponse;
+    const PATH: &'static str = "/auth/get";
+}
+
+#[async_trait]
+impl AuthApi for PlaidClient {
+    async fn auth_get(&self, req: AuthGetRequest) -> PlaidResult<AuthGetResponse> {
+        self.execute(req).await
+    }
+}
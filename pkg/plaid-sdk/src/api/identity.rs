@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+
+use crate::{
+    model::identity::{
+        IdentityGetRequest, IdentityGetResponse, IdentityMatchRequest, IdentityMatchResponse,
+    },
+    traits::{Endpoint, IdentityApi},
+    PlaidClient, PlaidResult,
+};
+
+impl Endpoint for IdentityGetRequest {
+    type Response = IdentityGetResponse;
+    const PATH: &'static str = "/identity/get";
+}
+
+impl Endpoint for IdentityMatchRequest {
+    type Response = IdentityMatchResponse;
+    const PATH: &'static str = "/identity/match";
+}
+
+#[async_trait]
+impl IdentityApi for PlaidClient {
+    async fn identity_get(&self, req: IdentityGetRequest) -> PlaidResult<IdentityGetResponse> {
+        self.execute(req).await
+    }
+
+    async fn identity_match(
+        &self,
+        req: IdentityMatchRequest,
+    ) -> PlaidResult<IdentityMatchResponse> {
+        self.execute(req).await
+    }
+}
@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+
+use crate::{
+    model::institutions::{
+        InstitutionsGetByIdRequest, InstitutionsGetByIdResponse, InstitutionsGetRequest,
+        InstitutionsGetResponse, InstitutionsSearchRequest, InstitutionsSearchResponse,
+    },
+    traits::{Endpoint, InstitutionsApi},
+    PlaidClient, PlaidResult,
+};
+
+impl Endpoint for InstitutionsGetRequest {
+    type Response = InstitutionsGetResponse;
+    const PATH: &'static str = "/institutions/get";
+}
+
+impl Endpoint for InstitutionsSearchRequest {
+    type Response = InstitutionsSearchResponse;
+    const PATH: &'static str = "/institutions/search";
+}
+
+impl Endpoint for InstitutionsGetByIdRequest {
+    type Response = InstitutionsGetByIdResponse;
+    const PATH: &'static str = "/institutions/get_by_id";
+}
+
+#[async_trait]
+impl InstitutionsApi for PlaidClient {
+    async fn institutions_get(
+        &self,
+        req: InstitutionsGetRequest,
+    ) -> PlaidResult<InstitutionsGetResponse> {
+        self.execute(req).await
+    }
+
+    async fn institutions_search(
+        &self,
+        req: InstitutionsSearchRequest,
+    ) -> PlaidResult<InstitutionsSearchResponse> {
+        self.execute(req).await
+    }
+
+    async fn institutions_get_by_id(
+        &self,
+        req: InstitutionsGetByIdRequest,
+    ) -> PlaidResult<InstitutionsGetByIdResponse> {
+        self.execute(req).await
+    }
+}
@@ -1,27 +1,67 @@
 use crate::{
-    model::{
-        error::PlaidErrorResponse,
-        items::{ItemPublicTokenExchangeRequest, ItemPublicTokenExchangeResponse},
+    model::items::{
+        ItemAccessTokenInvalidateRequest, ItemAccessTokenInvalidateResponse, ItemGetRequest,
+        ItemGetResponse, ItemPublicTokenExchangeRequest, ItemPublicTokenExchangeResponse,
+        ItemRemoveRequest, ItemRemoveResponse, ItemWebhookUpdateRequest, ItemWebhookUpdateResponse,
     },
-    traits::{ItemsApi, PlaidApi},
-    Either, PlaidClient, PlaidResult,
+    traits::{Endpoint, ItemsApi},
+    PlaidClient, PlaidResult,
 };
 use async_trait::async_trait;
 
-impl PlaidApi<ItemPublicTokenExchangeRequest, ItemPublicTokenExchangeResponse> for PlaidClient {}
+impl Endpoint for ItemPublicTokenExchangeRequest {
+    type Response = ItemPublicTokenExchangeResponse;
+    const PATH: &'static str = "/item/public_token/exchange";
+}
+
+impl Endpoint for ItemGetRequest {
+    type Response = ItemGetResponse;
+    const PATH: &'static str = "/item/get";
+}
+
+impl Endpoint for ItemRemoveRequest {
+    type Response = ItemRemoveResponse;
+    const PATH: &'static str = "/item/remove";
+}
+
+impl Endpoint for ItemWebhookUpdateRequest {
+    type Response = ItemWebhookUpdateResponse;
+    const PATH: &'static str = "/item/webhook/update";
+}
+
+impl Endpoint for ItemAccessTokenInvalidateRequest {
+    type Response = ItemAccessTokenInvalidateResponse;
+    const PATH: &'static str = "/item/access_token/invalidate";
+}
 
 #[async_trait]
 impl ItemsApi for PlaidClient {
     async fn item_public_token_exchange(
         &self,
         req: ItemPublicTokenExchangeRequest,
-    ) -> PlaidResult<Either<ItemPublicTokenExchangeResponse, PlaidErrorResponse>> {
-        let item_public_token_exchange_url = format!(
-            "{}/item/public_token/exchange",
-            self.plaid_env.get_base_url()
-        );
-
-        self.call(&self.reqwest_client, req, &item_public_token_exchange_url)
-            .await
+    ) -> PlaidResult<ItemPublicTokenExchangeResponse> {
+        self.execute(req).await
+    }
+
+    async fn item_get(&self, req: ItemGetRequest) -> PlaidResult<ItemGetResponse> {
+        self.execute(req).await
+    }
+
+    async fn item_remove(&self, req: ItemRemoveRequest) -> PlaidResult<ItemRemoveResponse> {
+        self.execute(req).await
+    }
+
+    async fn item_webhook_update(
+        &self,
+        req: ItemWebhookUpdateRequest,
+    ) -> PlaidResult<ItemWebhookUpdateResponse> {
+        self.execute(req).await
+    }
+
+    async fn item_access_token_invalidate(
+        &self,
+        req: ItemAccessTokenInvalidateRequest,
+    ) -> PlaidResult<ItemAccessTokenInvalidateResponse> {
+        self.execute(req).await
     }
 }
@@ -1,24 +1,22 @@
 use async_trait::async_trait;
 
 use crate::{
-    model::{
-        error::PlaidErrorResponse,
-        link::{LinkTokenCreateRequest, LinkTokenCreateResponse},
-    },
-    traits::{LinkApi, PlaidApi},
-    Either, PlaidClient, PlaidResult,
+    model::link::{LinkTokenCreateRequest, LinkTokenCreateResponse},
+    traits::{Endpoint, LinkApi},
+    PlaidClient, PlaidResult,
 };
 
-impl PlaidApi<LinkTokenCreateRequest, LinkTokenCreateResponse> for PlaidClient {}
+impl Endpoint for LinkTokenCreateRequest {
+    type Response = LinkTokenCreateResponse;
+    const PATH: &'static str = "/link/token/create";
+}
 
 #[async_trait]
 impl LinkApi for PlaidClient {
     async fn link_token_create(
         &self,
         req: LinkTokenCreateRequest,
-    ) -> PlaidResult<Either<LinkTokenCreateResponse, PlaidErrorResponse>> {
-        let link_token_create_url = format!("{}/link/token/create", self.plaid_env.get_base_url());
-        self.call(&self.reqwest_client, req, &link_token_create_url)
-            .await
+    ) -> PlaidResult<LinkTokenCreateResponse> {
+        self.execute(req).await
     }
 }
@@ -1,4 +1,9 @@
 pub mod accounts;
+pub mod auth;
+pub mod identity;
+pub mod institutions;
 pub mod items;
 pub mod link;
+pub mod sandbox;
+pub mod transactions;
 pub mod transfer;
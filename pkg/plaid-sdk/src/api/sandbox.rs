@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+
+use crate::{
+    model::sandbox::{
+        SandboxItemFireWebhookRequest, SandboxItemFireWebhookResponse,
+        SandboxItemResetLoginRequest, SandboxItemResetLoginResponse,
+        SandboxPublicTokenCreateRequest, SandboxPublicTokenCreateResponse,
+        SandboxTransferSimulateRequest, SandboxTransferSimulateResponse,
+    },
+    traits::{Endpoint, SandboxApi},
+    PlaidClient, PlaidResult,
+};
+
+impl Endpoint for SandboxPublicTokenCreateRequest {
+    type Response = SandboxPublicTokenCreateResponse;
+    const PATH: &'static str = "/sandbox/public_token/create";
+    const SANDBOX_ONLY: bool = true;
+}
+
+impl Endpoint for SandboxItemResetLoginRequest {
+    type Response = SandboxItemResetLoginResponse;
+    const PATH: &'static str = "/sandbox/item/reset_login";
+    const SANDBOX_ONLY: bool = true;
+}
+
+impl Endpoint for SandboxItemFireWebhookRequest {
+    type Response = SandboxItemFireWebhookResponse;
+    const PATH: &'static str = "/sandbox/item/fire_webhook";
+    const SANDBOX_ONLY: bool = true;
+}
+
+impl Endpoint for SandboxTransferSimulateRequest {
+    type Response = SandboxTransferSimulateResponse;
+    const PATH: &'static str = "/sandbox/transfer/simulate";
+    const SANDBOX_ONLY: bool = true;
+}
+
+#[async_trait]
+impl SandboxApi for PlaidClient {
+    async fn sandbox_public_token_create(
+        &self,
+        req: SandboxPublicTokenCreateRequest,
+    ) -> PlaidResult<SandboxPublicTokenCreateResponse> {
+        self.execute(req).await
+    }
+
+    async fn sandbox_item_reset_login(
+        &self,
+        req: SandboxItemResetLoginRequest,
+    ) -> PlaidResult<SandboxItemResetLoginResponse> {
+        self.execute(req).await
+    }
+
+    async fn sandbox_item_fire_webhook(
+        &self,
+        req: SandboxItemFireWebhookRequest,
+    ) -> PlaidResult<SandboxItemFireWebhookResponse> {
+        self.execute(req).await
+    }
+
+    async fn sandbox_transfer_simulate(
+        &self,
+        req: SandboxTransferSimulateRequest,
+    ) -> PlaidResult<SandboxTransferSimulateResponse> {
+        self.execute(req).await
+    }
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use crate::{
+    model::transactions::{TransactionsSyncRequest, TransactionsSyncResponse},
+    traits::{Endpoint, TransactionsApi},
+    PlaidClient, PlaidResult,
+};
+
+impl Endpoint for TransactionsSyncRequest {
+    type Response = TransactionsSyncResponse;
+    const PATH: &'static str = "/transactions/sync";
+}
+
+#[async_trait]
+impl TransactionsApi for PlaidClient {
+    async fn transactions_sync(
+        &self,
+        req: TransactionsSyncRequest,
+    ) -> PlaidResult<TransactionsSyncResponse> {
+        self.execute(req).await
+    }
+}
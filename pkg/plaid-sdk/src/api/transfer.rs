@@ -0,0 +1,245 @@
+use std::collections::VecDeque;
+
+use async_trait::async_trait;
+
+use crate::{
+    model::transfer::{
+        TransferAuthorizationCreateRequest, TransferAuthorizationCreateResponse,
+        TransferCancelRequest, TransferCancelResponse, TransferCreateRequest,
+        TransferCreateResponse, TransferEvent, TransferEventListRequest, TransferEventListResponse,
+        TransferEventSyncRequest, TransferEventSyncResponse, TransferGetRequest,
+        TransferGetResponse, TransferListRequest, TransferListResponse,
+    },
+    retry::RetryPolicy,
+    traits::{Endpoint, TransferApi},
+    PlaidClient, PlaidResult,
+};
+
+impl Endpoint for TransferAuthorizationCreateRequest {
+    type Response = TransferAuthorizationCreateResponse;
+    const PATH: &'static str = "/transfer/authorization/create";
+
+    fn retry_policy(&self, client_policy: &RetryPolicy) -> RetryPolicy {
+        client_policy.for_money_movement(self.idempotency_key.as_deref())
+    }
+}
+
+impl Endpoint for TransferCreateRequest {
+    type Response = TransferCreateResponse;
+    const PATH: &'static str = "/transfer/create";
+
+    fn retry_policy(&self, client_policy: &RetryPolicy) -> RetryPolicy {
+        client_policy.for_money_movement(self.idempotency_key.as_deref())
+    }
+}
+
+impl Endpoint for TransferGetRequest {
+    type Response = TransferGetResponse;
+    const PATH: &'static str = "/transfer/get";
+}
+
+impl Endpoint for TransferListRequest {
+    type Response = TransferListResponse;
+    const PATH: &'static str = "/transfer/list";
+}
+
+impl Endpoint for TransferCancelRequest {
+    type Response = TransferCancelResponse;
+    const PATH: &'static str = "/transfer/cancel";
+}
+
+impl Endpoint for TransferEventListRequest {
+    type Response = TransferEventListResponse;
+    const PATH: &'static str = "/transfer/event/list";
+}
+
+impl Endpoint for TransferEventSyncRequest {
+    type Response = TransferEventSyncResponse;
+    const PATH: &'static str = "/transfer/event/sync";
+}
+
+#[async_trait]
+impl TransferApi for PlaidClient {
+    async fn transfer_authorization_create(
+        &self,
+        req: TransferAuthorizationCreateRequest,
+    ) -> PlaidResult<TransferAuthorizationCreateResponse> {
+        self.execute(req).await
+    }
+
+    async fn transfer_create(
+        &self,
+        req: TransferCreateRequest,
+    ) -> PlaidResult<TransferCreateResponse> {
+        self.execute(req).await
+    }
+
+    async fn transfer_get(&self, req: TransferGetRequest) -> PlaidResult<TransferGetResponse> {
+        self.execute(req).await
+    }
+
+    async fn transfer_list(&self, req: TransferListRequest) -> PlaidResult<TransferListResponse> {
+        self.execute(req).await
+    }
+
+    async fn transfer_cancel(
+        &self,
+        req: TransferCancelRequest,
+    ) -> PlaidResult<TransferCancelResponse> {
+        self.execute(req).await
+    }
+
+    async fn transfer_event_list(
+        &self,
+        req: TransferEventListRequest,
+    ) -> PlaidResult<TransferEventListResponse> {
+        self.execute(req).await
+    }
+
+    async fn transfer_event_sync(
+        &self,
+        req: TransferEventSyncRequest,
+    ) -> PlaidResult<TransferEventSyncResponse> {
+        self.execute(req).await
+    }
+}
+
+/// Pull-based stream over `/transfer/event/sync`.
+///
+/// Events are yielded in `event_id` order. After handling an event, persist
+/// [`TransferEventStream::after_id`] so that reconciliation can resume from
+/// the same point later.
+pub struct TransferEventStream<'a, C: TransferApi + ?Sized> {
+    client: &'a C,
+    req: TransferEventSyncRequest,
+    buffered: VecDeque<TransferEvent>,
+    has_more: bool,
+}
+
+impl<'a, C: TransferApi + ?Sized> TransferEventStream<'a, C> {
+    pub fn new(client: &'a C, req: TransferEventSyncRequest) -> Self {
+        Self {
+            client,
+            req,
+            buffered: VecDeque::new(),
+            has_more: true,
+        }
+    }
+
+    /// The `event_id` of the last event yielded, or the starting `after_id`
+    pub fn after_id(&self) -> u64 {
+        self.req.after_id
+    }
+
+    /// Returns the next event, fetching another page when the current one is
+    /// exhausted. `Ok(None)` means the feed has been drained for now.
+    pub async fn next(&mut self) -> PlaidResult<Option<TransferEvent>> {
+        if self.buffered.is_empty() && self.has_more {
+            let page = self.client.transfer_event_sync(self.req.clone()).await?;
+            self.has_more = page.has_more;
+            self.buffered.extend(page.transfer_events);
+        }
+
+        match self.buffered.pop_front() {
+            Some(event) => {
+                self.req.after_id = event.event_id;
+                Ok(Some(event))
+            }
+            None => {
+                // allow callers to poll again later for newly published events
+                self.has_more = true;
+                Ok(None)
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "test-util"))]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{sandbox::SandboxTransferSimulateRequest, transfer::TransferEventType},
+        testing::{FakeItem, FakePlaid},
+        traits::{Endpoint, SandboxApi},
+    };
+
+    fn syncs(plaid: &FakePlaid) -> usize {
+        plaid
+            .calls()
+            .into_iter()
+            .filter(|path| *path == TransferEventSyncRequest::PATH)
+            .count()
+    }
+
+    async fn simulate(plaid: &FakePlaid, transfer_id: &str, event_type: TransferEventType) {
+        plaid
+            .sandbox_transfer_simulate(SandboxTransferSimulateRequest {
+                transfer_id: transfer_id.to_string(),
+                event_type,
+                failure_reason: None,
+                test_clock_id: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn stream_pages_through_events_and_polls_again_once_drained() {
+        let plaid = FakePlaid::new();
+        let access_token = plaid.add_item(FakeItem::default());
+        let authorization = plaid
+            .transfer_authorization_create(TransferAuthorizationCreateRequest {
+                access_token: access_token.clone(),
+                account_id: "checking-0000".into(),
+                amount: "5.00".parse().unwrap(),
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .authorization;
+        let transfer = plaid
+            .transfer_create(TransferCreateRequest {
+                access_token,
+                account_id: "checking-0000".into(),
+                authorization_id: authorization.id,
+                description: "payment".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .transfer;
+        simulate(&plaid, &transfer.id, TransferEventType::Posted).await;
+
+        let mut stream = TransferEventStream::new(
+            &plaid,
+            TransferEventSyncRequest {
+                after_id: 0,
+                count: Some(1),
+            },
+        );
+
+        let event = stream.next().await.unwrap().unwrap();
+        assert_eq!(event.event_type, TransferEventType::Pending);
+        assert_eq!(stream.after_id(), 1);
+
+        // the first page had more, so a second one is fetched
+        let event = stream.next().await.unwrap().unwrap();
+        assert_eq!(event.event_type, TransferEventType::Posted);
+        assert_eq!(stream.after_id(), 2);
+        assert_eq!(syncs(&plaid), 2);
+
+        // the second page was the last one, so the feed is drained
+        assert!(stream.next().await.unwrap().is_none());
+        assert_eq!(syncs(&plaid), 2);
+
+        // and the next call polls again
+        assert!(stream.next().await.unwrap().is_none());
+        assert_eq!(syncs(&plaid), 3);
+
+        simulate(&plaid, &transfer.id, TransferEventType::Settled).await;
+        let event = stream.next().await.unwrap().unwrap();
+        assert_eq!(event.event_type, TransferEventType::Settled);
+        assert_eq!(stream.after_id(), 3);
+        assert_eq!(syncs(&plaid), 4);
+    }
+}
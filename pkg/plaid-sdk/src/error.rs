@@ -1,3 +1,8 @@
+use crate::{
+    model::{common::Product, error::PlaidErrorResponse, link::CountryCode},
+    PlaidEnv,
+};
+
 #[derive(Debug, thiserror::Error)]
 pub enum PlaidError {
     #[error("Invalid date of birth: {0}")]
@@ -6,9 +11,92 @@ pub enum PlaidError {
     #[error("Invalid phone number: {0}")]
     InvalidPhoneNumber(String),
 
+    #[error("Invalid region {region} for country {country:?}")]
+    InvalidRegion {
+        region: String,
+        country: CountryCode,
+    },
+
+    #[error("Invalid postal code {postal_code} for country {country:?}")]
+    InvalidPostalCode {
+        postal_code: String,
+        country: CountryCode,
+    },
+
+    #[error("A non-empty client_name is required to create a link token")]
+    EmptyClientName,
+
+    #[error("At least one country code is required to create a link token")]
+    NoCountryCodes,
+
+    #[error("At least one product is required to create a link token")]
+    NoProducts,
+
+    #[error("Only one of redirect_uri and android_package_name may be set")]
+    ConflictingRedirect,
+
+    #[error("Product {0:?} is listed more than once across products, required_if_supported_products and optional_products")]
+    DuplicateProduct(Product),
+
+    #[error("Invalid Plaid environment: {0}")]
+    InvalidEnv(String),
+
+    #[error("Invalid value for the {0} header")]
+    InvalidHeader(&'static str),
+
+    #[error("{endpoint} is only available in the sandbox environment, not {env:?}")]
+    SandboxOnly { endpoint: String, env: PlaidEnv },
+
+    #[error("Plaid API error {:?}: {}", .0.error_code, .0.error_message)]
+    Api(Box<PlaidErrorResponse>),
+
+    #[error("Unexpected HTTP status {0} from Plaid")]
+    UnexpectedStatus(u16),
+
     #[error(transparent)]
     Reqwest(#[from] reqwest::Error),
 
     #[error(transparent)]
     Serde(#[from] serde_json::Error),
 }
+
+impl PlaidError {
+    /// Plaid-side failures that may go away on their own, plus timeouts and
+    /// connection errors
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            PlaidError::Api(failure) => failure.is_retryable(),
+            PlaidError::UnexpectedStatus(status) => *status >= 500,
+            PlaidError::Reqwest(err) => {
+                err.is_timeout()
+                    || err.is_connect()
+                    || err
+                        .status()
+                        .map_or(false, |status| status.is_server_error())
+            }
+            _ => false,
+        }
+    }
+
+    /// The Plaid `request_id` of a failed API call, for support tickets
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            PlaidError::Api(failure) => Some(&failure.request_id),
+            _ => None,
+        }
+    }
+
+    pub fn requires_user_action(&self) -> bool {
+        match self {
+            PlaidError::Api(failure) => failure.requires_user_action(),
+            _ => false,
+        }
+    }
+
+    pub fn is_item_login_required(&self) -> bool {
+        match self {
+            PlaidError::Api(failure) => failure.is_item_login_required(),
+            _ => false,
+        }
+    }
+}
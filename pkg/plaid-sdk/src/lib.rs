@@ -1,41 +1,68 @@
+use std::time::Duration;
+
 use error::PlaidError;
-use serde::{Deserialize, Serialize};
+use model::secret::ClientSecret;
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    Url,
+};
+use retry::RetryPolicy;
+use tracing::{field, info_span, warn, Instrument};
+use traits::Endpoint;
 
 pub mod api;
 pub mod error;
 pub mod model;
+pub mod retry;
+#[cfg(feature = "test-util")]
+pub mod testing;
 pub mod traits;
+mod transport;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PlaidEnv {
     Sandbox,
     Production,
+    /// Any other host serving Plaid's API, e.g. a local stand-in server
+    Custom(Url),
 }
 
 impl PlaidEnv {
-    pub(crate) fn get_base_url(&self) -> &'static str {
+    pub(crate) fn get_base_url(&self) -> &str {
         match self {
             PlaidEnv::Sandbox => "https://sandbox.plaid.com",
             PlaidEnv::Production => "https://production.plaid.com",
+            PlaidEnv::Custom(url) => url.as_str().trim_end_matches('/'),
         }
     }
-}
 
-#[derive(Debug, Serialize, Deserialize)]
-pub enum Either<L, R> {
-    Left(L),
-    Right(R),
+    /// Also true for a `Custom` URL that points at Plaid's production host,
+    /// so sandbox-only calls cannot reach production through it
+    pub fn is_production(&self) -> bool {
+        match self {
+            PlaidEnv::Sandbox => false,
+            PlaidEnv::Production => true,
+            PlaidEnv::Custom(url) => url.host_str().map_or(false, |host| {
+                host.trim_end_matches('.') == "production.plaid.com"
+            }),
+        }
+    }
 }
 
 pub type PlaidResult<T> = std::result::Result<T, PlaidError>;
 
+/// The API version pinned by default, matching the Node quickstart server
+pub const PLAID_VERSION: &str = "2020-09-14";
+
 pub struct PlaidClient {
     pub plaid_client_id: String,
-    pub plaid_secret: String,
+    pub plaid_secret: ClientSecret,
     pub plaid_env: PlaidEnv,
-    pub plaid_redirect_uri: String,
-    pub plaid_android_package_name: String,
+    pub plaid_redirect_uri: Option<String>,
+    pub plaid_android_package_name: Option<String>,
     reqwest_client: reqwest::Client,
+    retry_policy: RetryPolicy,
+    headers: HeaderMap,
 }
 
 impl PlaidClient {
@@ -45,24 +72,377 @@ impl PlaidClient {
         plaid_env: &str,
         plaid_redirect_uri: &str,
         plaid_android_package_name: &str,
-    ) -> Self {
+    ) -> PlaidResult<Self> {
+        PlaidClient::builder(plaid_client_id, plaid_secret, plaid_env.try_into()?)
+            .redirect_uri(plaid_redirect_uri)
+            .android_package_name(plaid_android_package_name)
+            .build()
+    }
+
+    pub fn builder(
+        plaid_client_id: &str,
+        plaid_secret: &str,
+        plaid_env: PlaidEnv,
+    ) -> PlaidClientBuilder {
+        PlaidClientBuilder::new(plaid_client_id, plaid_secret, plaid_env)
+    }
+
+    /// Sends a request to the endpoint its type is bound to
+    pub async fn execute<E: Endpoint>(&self, req: E) -> PlaidResult<E::Response> {
+        if E::SANDBOX_ONLY && self.plaid_env.is_production() {
+            return Err(PlaidError::SandboxOnly {
+                endpoint: E::PATH.to_string(),
+                env: self.plaid_env.clone(),
+            });
+        }
+
+        let url = format!("{}{}", self.plaid_env.get_base_url(), E::PATH);
+        let retry_policy = req.retry_policy(&self.retry_policy);
+        self.call(req, &url, &retry_policy).await
+    }
+
+    /// Sends `req`, re-sending it as long as `retry_policy` allows. Each
+    /// attempt gets its own span so its outcome is not mistaken for another's.
+    async fn call<E: Endpoint>(
+        &self,
+        req: E,
+        url: &str,
+        retry_policy: &RetryPolicy,
+    ) -> PlaidResult<E::Response> {
+        let span = info_span!("plaid_call", endpoint = url);
+
+        let mut attempt = 1;
+        loop {
+            let attempt_span = info_span!(
+                parent: &span,
+                "plaid_attempt",
+                attempt,
+                status = field::Empty,
+                latency_ms = field::Empty,
+                request_id = field::Empty,
+                error_code = field::Empty,
+            );
+            match transport::send::<E, E::Response>(&self.reqwest_client, &self.headers, &req, url)
+                .instrument(attempt_span.clone())
+                .await
+            {
+                Err(err)
+                    if attempt < retry_policy.max_attempts && (retry_policy.retry_on)(&err) =>
+                {
+                    let delay = retry_policy.delay(attempt);
+                    warn!(
+                        parent: &attempt_span,
+                        error = %err,
+                        request_id = err.request_id(),
+                        ?delay,
+                        "retrying Plaid request"
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+}
+
+pub struct PlaidClientBuilder {
+    plaid_client_id: String,
+    plaid_secret: ClientSecret,
+    plaid_env: PlaidEnv,
+    plaid_redirect_uri: Option<String>,
+    plaid_android_package_name: Option<String>,
+    plaid_version: String,
+    retry_policy: RetryPolicy,
+    reqwest_client: Option<reqwest::Client>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxy: Option<reqwest::Proxy>,
+    user_agent: Option<String>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+}
+
+impl PlaidClientBuilder {
+    pub fn new(plaid_client_id: &str, plaid_secret: &str, plaid_env: PlaidEnv) -> Self {
         Self {
             plaid_client_id: plaid_client_id.into(),
             plaid_secret: plaid_secret.into(),
-            plaid_env: plaid_env.into(),
-            plaid_redirect_uri: plaid_redirect_uri.into(),
-            plaid_android_package_name: plaid_android_package_name.into(),
-            reqwest_client: reqwest::Client::new(),
+            plaid_env,
+            plaid_redirect_uri: None,
+            plaid_android_package_name: None,
+            plaid_version: PLAID_VERSION.into(),
+            retry_policy: RetryPolicy::default(),
+            reqwest_client: None,
+            connect_timeout: None,
+            timeout: None,
+            proxy: None,
+            user_agent: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
         }
     }
+
+    pub fn redirect_uri(mut self, plaid_redirect_uri: &str) -> Self {
+        self.plaid_redirect_uri = Some(plaid_redirect_uri.into());
+        self
+    }
+
+    pub fn android_package_name(mut self, plaid_android_package_name: &str) -> Self {
+        self.plaid_android_package_name = Some(plaid_android_package_name.into());
+        self
+    }
+
+    pub fn plaid_version(mut self, plaid_version: &str) -> Self {
+        self.plaid_version = plaid_version.into();
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Uses a preconfigured client as is; the HTTP settings below are then ignored
+    pub fn reqwest_client(mut self, reqwest_client: reqwest::Client) -> Self {
+        self.reqwest_client = Some(reqwest_client);
+        self
+    }
+
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Total time allowed for a single attempt, from connecting until the
+    /// response body has been read
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    pub fn pool_idle_timeout(mut self, pool_idle_timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(pool_idle_timeout);
+        self
+    }
+
+    pub fn build(self) -> PlaidResult<PlaidClient> {
+        let headers = auth_headers(
+            &self.plaid_client_id,
+            self.plaid_secret.expose_secret(),
+            &self.plaid_version,
+        )?;
+
+        let reqwest_client = match self.reqwest_client {
+            Some(reqwest_client) => reqwest_client,
+            None => {
+                let mut builder = reqwest::Client::builder();
+                if let Some(connect_timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(connect_timeout);
+                }
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+                if let Some(user_agent) = self.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+                if let Some(max) = self.pool_max_idle_per_host {
+                    builder = builder.pool_max_idle_per_host(max);
+                }
+                if let Some(pool_idle_timeout) = self.pool_idle_timeout {
+                    builder = builder.pool_idle_timeout(pool_idle_timeout);
+                }
+                builder.build().map_err(PlaidError::Reqwest)?
+            }
+        };
+
+        Ok(PlaidClient {
+            plaid_client_id: self.plaid_client_id,
+            plaid_secret: self.plaid_secret,
+            plaid_env: self.plaid_env,
+            plaid_redirect_uri: self.plaid_redirect_uri,
+            plaid_android_package_name: self.plaid_android_package_name,
+            reqwest_client,
+            retry_policy: self.retry_policy,
+            headers,
+        })
+    }
 }
 
-impl From<&str> for PlaidEnv {
-    fn from(env: &str) -> Self {
+/// Credentials and API version sent with every request
+fn auth_headers(client_id: &str, secret: &str, plaid_version: &str) -> PlaidResult<HeaderMap> {
+    let header_value = |name: &'static str, value: &str| {
+        HeaderValue::from_str(value).map_err(|_| PlaidError::InvalidHeader(name))
+    };
+
+    let mut secret = header_value("PLAID-SECRET", secret)?;
+    secret.set_sensitive(true);
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "PLAID-CLIENT-ID",
+        header_value("PLAID-CLIENT-ID", client_id)?,
+    );
+    headers.insert("PLAID-SECRET", secret);
+    headers.insert(
+        "Plaid-Version",
+        header_value("Plaid-Version", plaid_version)?,
+    );
+    Ok(headers)
+}
+
+/// Accepts `sandbox`, `production`, or an `http(s)://` base URL
+impl TryFrom<&str> for PlaidEnv {
+    type Error = PlaidError;
+
+    fn try_from(env: &str) -> PlaidResult<Self> {
         match env {
-            "sandbox" => PlaidEnv::Sandbox,
-            "production" => PlaidEnv::Production,
-            _ => PlaidEnv::Production,
+            "sandbox" => Ok(PlaidEnv::Sandbox),
+            "production" => Ok(PlaidEnv::Production),
+            _ if env.starts_with("http://") || env.starts_with("https://") => Url::parse(env)
+                .map(PlaidEnv::Custom)
+                .map_err(|_| PlaidError::InvalidEnv(env.to_string())),
+            _ => Err(PlaidError::InvalidEnv(env.to_string())),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::transfer::{TransferCreateRequest, TransferGetRequest};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    /// Answers every request with a retryable `INTERNAL_SERVER_ERROR` and
+    /// returns its base URL and the number of requests served
+    async fn failing_plaid() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let served = requests.clone();
+        tokio::spawn(async move {
+            let body = serde_json::json!({
+                "display_message": null,
+                "documentation_url": "https://plaid.com/docs/errors/",
+                "error_code": "INTERNAL_SERVER_ERROR",
+                "error_message": "an unexpected error occurred",
+                "error_type": "API_ERROR",
+                "request_id": "abc123",
+            })
+            .to_string();
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0; 4096];
+                // read the headers and the body before answering
+                while let Ok(n @ 1..) = socket.read(&mut buf).await {
+                    request.extend_from_slice(&buf[..n]);
+                    let text = String::from_utf8_lossy(&request);
+                    if let Some(end) = text.find("\r\n\r\n") {
+                        let length = text[..end]
+                            .lines()
+                            .find_map(|line| {
+                                line.to_lowercase()
+                                    .strip_prefix("content-length:")
+                                    .map(|v| v.trim().parse::<usize>().unwrap())
+                            })
+                            .unwrap_or(0);
+                        if request.len() >= end + 4 + length {
+                            break;
+                        }
+                    }
+                }
+                served.fetch_add(1, Ordering::SeqCst);
+                let response = format!(
+                    "HTTP/1.1 500 Internal Server Error\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    fn client(url: &str) -> PlaidClient {
+        PlaidClient::builder("client-id", "secret", url.try_into().unwrap())
+            .retry_policy(RetryPolicy {
+                base_delay: Duration::from_millis(1),
+                jitter: false,
+                ..Default::default()
+            })
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn transient_failures_are_retried_up_to_max_attempts() {
+        let (url, requests) = failing_plaid().await;
+
+        let err = client(&url)
+            .execute(TransferGetRequest::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.request_id(), Some("abc123"));
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn money_movement_without_idempotency_key_is_sent_once() {
+        let (url, requests) = failing_plaid().await;
+        let client = client(&url);
+
+        client
+            .execute(TransferCreateRequest::default())
+            .await
+            .unwrap_err();
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        client
+            .execute(TransferCreateRequest {
+                idempotency_key: Some("key".into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(requests.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn custom_production_host_counts_as_production() {
+        let env = |url: &str| PlaidEnv::try_from(url).unwrap();
+
+        assert!(env("production").is_production());
+        assert!(env("https://production.plaid.com").is_production());
+        assert!(env("https://PRODUCTION.plaid.com./").is_production());
+        assert!(!env("sandbox").is_production());
+        assert!(!env("http://127.0.0.1:8081").is_production());
+    }
+}
@@ -1,7 +1,11 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use super::{common::Product, error::PlaidErrorResponse};
+use super::secret::AccessToken;
+use super::{
+    common::{Amount, Currency, Money, Product},
+    error::PlaidErrorResponse,
+};
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AccountBalanceGetRequestOptions {
@@ -10,9 +14,7 @@ pub struct AccountBalanceGetRequestOptions {
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AccountsBalanceGetRequest {
-    pub client_id: String,
-    pub secret: String,
-    pub access_token: String,
+    pub access_token: AccessToken,
     pub options: Option<AccountBalanceGetRequestOptions>,
 }
 
@@ -24,6 +26,8 @@ pub enum AccountType {
     Loan,
     Investment,
     Other,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -39,6 +43,8 @@ pub enum AccountVerificationStatus {
     DatabaseInsightsPass,
     DatabaseInsightsPassWithCaution,
     DatabaseInsightsFail,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -58,6 +64,9 @@ pub enum AccountNumberFormat {
     Valid,
     Invalid,
     Unknown,
+    /// Plaid already uses `unknown` for this field, so unseen values land here
+    #[serde(untagged)]
+    Unrecognized(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -73,18 +82,37 @@ pub enum AccountHolderCategory {
     Business,
     Personal,
     Unrecognized,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AccountBalance {
-    pub available: Option<f64>,
-    pub current: Option<f64>,
-    pub limit: Option<f64>,
-    pub iso_currency_code: Option<String>,
-    pub unofficial_currency_code: Option<String>,
+    pub available: Option<Amount>,
+    pub current: Option<Amount>,
+    pub limit: Option<Amount>,
+    #[serde(flatten, with = "super::common::currency_codes")]
+    pub currency: Option<Currency>,
     pub last_updated_datetime: Option<DateTime<Utc>>,
 }
 
+impl AccountBalance {
+    pub fn available_money(&self) -> Option<Money> {
+        self.available
+            .map(|amount| Money::new(amount, self.currency.clone()))
+    }
+
+    pub fn current_money(&self) -> Option<Money> {
+        self.current
+            .map(|amount| Money::new(amount, self.currency.clone()))
+    }
+
+    pub fn limit_money(&self) -> Option<Money> {
+        self.limit
+            .map(|amount| Money::new(amount, self.currency.clone()))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Account {
     pub account_id: String,
@@ -106,6 +134,8 @@ pub struct Account {
 pub enum AccountUpdateType {
     Background,
     UserPresentRequired,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -127,3 +157,61 @@ pub struct AccountsGetResponse {
     pub item: AccountItem,
     pub request_id: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unseen_account_enums_decode() {
+        let account: Account = serde_json::from_value(serde_json::json!({
+            "account_id": "acc",
+            "balances": {
+                "available": 100,
+                "current": 110,
+                "limit": null,
+                "iso_currency_code": "USD",
+                "unofficial_currency_code": null,
+            },
+            "mask": "0000",
+            "name": "Plaid Checking",
+            "official_name": null,
+            "type": "some_new_type",
+            "subtype": "checking",
+            "verification_status": "some_new_status",
+            "verification_insights": {
+                "network_status": {
+                    "has_numbers_match": true,
+                    "is_numbers_match_verified": true,
+                },
+                "previous_returns": {
+                    "has_previous_administrative_return": false,
+                },
+                "account_number_format": "some_new_format",
+            },
+            "persistent_account_id": "persistent",
+            "holder_category": "some_new_category",
+        }))
+        .unwrap();
+
+        assert_eq!(account.type_, AccountType::Unknown("some_new_type".into()));
+        assert_eq!(
+            account.verification_status,
+            Some(AccountVerificationStatus::Unknown("some_new_status".into()))
+        );
+        assert_eq!(
+            account.verification_insights.unwrap().account_number_format,
+            AccountNumberFormat::Unrecognized("some_new_format".into())
+        );
+        assert_eq!(
+            account.holder_category,
+            Some(AccountHolderCategory::Unknown("some_new_category".into()))
+        );
+    }
+
+    #[test]
+    fn literal_unknown_account_number_format_is_not_a_catch_all() {
+        let format: AccountNumberFormat = serde_json::from_str("\"unknown\"").unwrap();
+        assert_eq!(format, AccountNumberFormat::Unknown);
+    }
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use super::accounts::{Account, AccountItem};
+use super::secret::AccessToken;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthGetRequestOptions {
+    pub account_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthGetRequest {
+    pub access_token: AccessToken,
+    pub options: Option<AuthGetRequestOptions>,
+}
+
+/// US ACH numbers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AchNumbers {
+    pub account_id: String,
+    pub account: String,
+    pub routing: String,
+    pub wire_routing: Option<String>,
+    pub can_transfer_in: Option<bool>,
+    pub can_transfer_out: Option<bool>,
+}
+
+/// Canadian EFT numbers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EftNumbers {
+    pub account_id: String,
+    pub account: String,
+    pub institution: String,
+    pub branch: String,
+}
+
+/// IBAN and BIC, mostly for European accounts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InternationalNumbers {
+    pub account_id: String,
+    pub iban: String,
+    pub bic: String,
+}
+
+/// UK BACS numbers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BacsNumbers {
+    pub account_id: String,
+    pub account: String,
+    pub sort_code: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthNumbers {
+    pub ach: Vec<AchNumbers>,
+    pub eft: Vec<EftNumbers>,
+    pub international: Vec<InternationalNumbers>,
+    pub bacs: Vec<BacsNumbers>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuthGetResponse {
+    pub accounts: Vec<Account>,
+    pub numbers: AuthNumbers,
+    pub item: AccountItem,
+    pub request_id: String,
+}
@@ -1,3 +1,10 @@
+use std::{
+    fmt,
+    ops::{Add, Neg, Sub},
+    str::FromStr,
+};
+
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
@@ -34,4 +41,569 @@ pub enum Product {
     Statements,
     Transactions,
     Transfer,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A fixed-point decimal amount. Plaid sends amounts as JSON numbers; they are
+/// parsed from their shortest decimal representation, so `110.94` stays exactly
+/// `110.94` and amounts can be compared for equality.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Amount(Decimal);
+
+impl Amount {
+    pub const ZERO: Amount = Amount(Decimal::ZERO);
+
+    pub fn new(value: Decimal) -> Self {
+        Self(value)
+    }
+
+    pub fn as_decimal(&self) -> Decimal {
+        self.0
+    }
+
+    /// Rounds to `decimal_places` using banker's rounding
+    pub fn round_dp(&self, decimal_places: u32) -> Self {
+        Self(self.0.round_dp(decimal_places))
+    }
+}
+
+impl From<Decimal> for Amount {
+    fn from(value: Decimal) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Amount> for Decimal {
+    fn from(value: Amount) -> Self {
+        value.0
+    }
+}
+
+impl FromStr for Amount {
+    type Err = rust_decimal::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Decimal::from_str(s).map(Self)
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Add for Amount {
+    type Output = Amount;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Amount {
+    type Output = Amount;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Neg for Amount {
+    type Output = Amount;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl Serialize for Amount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self.0.to_f64() {
+            Some(value) => serializer.serialize_f64(value),
+            None => Err(serde::ser::Error::custom(format!(
+                "amount {} is not representable as a JSON number",
+                self.0
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // accepts both JSON numbers and decimal strings
+        <Decimal as Deserialize>::deserialize(deserializer).map(Self)
+    }
+}
+
+/// An amount together with the currency it is denominated in
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Money {
+    pub amount: Amount,
+    pub currency: Option<Currency>,
+}
+
+impl Money {
+    pub fn new(amount: Amount, currency: Option<Currency>) -> Self {
+        Self { amount, currency }
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.currency {
+            Some(currency) => write!(f, "{} {}", self.amount, currency),
+            None => write!(f, "{}", self.amount),
+        }
+    }
+}
+
+macro_rules! iso_currencies {
+    ($($variant:ident => ($code:literal, $minor_units:literal),)*) => {
+        /// Active ISO 4217 currencies, plus codes newer than this list
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum IsoCurrency {
+            $($variant,)*
+            Unknown(String),
+        }
+
+        impl IsoCurrency {
+            pub fn from_code(code: &str) -> Self {
+                match code {
+                    $($code => IsoCurrency::$variant,)*
+                    _ => IsoCurrency::Unknown(code.to_string()),
+                }
+            }
+
+            pub fn code(&self) -> &str {
+                match self {
+                    $(IsoCurrency::$variant => $code,)*
+                    IsoCurrency::Unknown(code) => code,
+                }
+            }
+
+            /// Number of decimal places amounts in this currency are quoted
+            /// with; unknown for codes missing from this list
+            pub fn minor_units(&self) -> Option<u32> {
+                match self {
+                    $(IsoCurrency::$variant => Some($minor_units),)*
+                    IsoCurrency::Unknown(_) => None,
+                }
+            }
+        }
+    };
+}
+
+iso_currencies! {
+    Aed => ("AED", 2),
+    Afn => ("AFN", 2),
+    All => ("ALL", 2),
+    Amd => ("AMD", 2),
+    Ang => ("ANG", 2),
+    Aoa => ("AOA", 2),
+    Ars => ("ARS", 2),
+    Aud => ("AUD", 2),
+    Awg => ("AWG", 2),
+    Azn => ("AZN", 2),
+    Bam => ("BAM", 2),
+    Bbd => ("BBD", 2),
+    Bdt => ("BDT", 2),
+    Bgn => ("BGN", 2),
+    Bhd => ("BHD", 3),
+    Bif => ("BIF", 0),
+    Bmd => ("BMD", 2),
+    Bnd => ("BND", 2),
+    Bob => ("BOB", 2),
+    Brl => ("BRL", 2),
+    Bsd => ("BSD", 2),
+    Btn => ("BTN", 2),
+    Bwp => ("BWP", 2),
+    Byn => ("BYN", 2),
+    Bzd => ("BZD", 2),
+    Cad => ("CAD", 2),
+    Cdf => ("CDF", 2),
+    Chf => ("CHF", 2),
+    Clf => ("CLF", 4),
+    Clp => ("CLP", 0),
+    Cny => ("CNY", 2),
+    Cop => ("COP", 2),
+    Crc => ("CRC", 2),
+    Cup => ("CUP", 2),
+    Cve => ("CVE", 2),
+    Czk => ("CZK", 2),
+    Djf => ("DJF", 0),
+    Dkk => ("DKK", 2),
+    Dop => ("DOP", 2),
+    Dzd => ("DZD", 2),
+    Egp => ("EGP", 2),
+    Ern => ("ERN", 2),
+    Etb => ("ETB", 2),
+    Eur => ("EUR", 2),
+    Fjd => ("FJD", 2),
+    Fkp => ("FKP", 2),
+    Gbp => ("GBP", 2),
+    Gel => ("GEL", 2),
+    Ghs => ("GHS", 2),
+    Gip => ("GIP", 2),
+    Gmd => ("GMD", 2),
+    Gnf => ("GNF", 0),
+    Gtq => ("GTQ", 2),
+    Gyd => ("GYD", 2),
+    Hkd => ("HKD", 2),
+    Hnl => ("HNL", 2),
+    Htg => ("HTG", 2),
+    Huf => ("HUF", 2),
+    Idr => ("IDR", 2),
+    Ils => ("ILS", 2),
+    Inr => ("INR", 2),
+    Iqd => ("IQD", 3),
+    Irr => ("IRR", 2),
+    Isk => ("ISK", 0),
+    Jmd => ("JMD", 2),
+    Jod => ("JOD", 3),
+    Jpy => ("JPY", 0),
+    Kes => ("KES", 2),
+    Kgs => ("KGS", 2),
+    Khr => ("KHR", 2),
+    Kmf => ("KMF", 0),
+    Kpw => ("KPW", 2),
+    Krw => ("KRW", 0),
+    Kwd => ("KWD", 3),
+    Kyd => ("KYD", 2),
+    Kzt => ("KZT", 2),
+    Lak => ("LAK", 2),
+    Lbp => ("LBP", 2),
+    Lkr => ("LKR", 2),
+    Lrd => ("LRD", 2),
+    Lsl => ("LSL", 2),
+    Lyd => ("LYD", 3),
+    Mad => ("MAD", 2),
+    Mdl => ("MDL", 2),
+    Mga => ("MGA", 2),
+    Mkd => ("MKD", 2),
+    Mmk => ("MMK", 2),
+    Mnt => ("MNT", 2),
+    Mop => ("MOP", 2),
+    Mru => ("MRU", 2),
+    Mur => ("MUR", 2),
+    Mvr => ("MVR", 2),
+    Mwk => ("MWK", 2),
+    Mxn => ("MXN", 2),
+    Myr => ("MYR", 2),
+    Mzn => ("MZN", 2),
+    Nad => ("NAD", 2),
+    Ngn => ("NGN", 2),
+    Nio => ("NIO", 2),
+    Nok => ("NOK", 2),
+    Npr => ("NPR", 2),
+    Nzd => ("NZD", 2),
+    Omr => ("OMR", 3),
+    Pab => ("PAB", 2),
+    Pen => ("PEN", 2),
+    Pgk => ("PGK", 2),
+    Php => ("PHP", 2),
+    Pkr => ("PKR", 2),
+    Pln => ("PLN", 2),
+    Pyg => ("PYG", 0),
+    Qar => ("QAR", 2),
+    Ron => ("RON", 2),
+    Rsd => ("RSD", 2),
+    Rub => ("RUB", 2),
+    Rwf => ("RWF", 0),
+    Sar => ("SAR", 2),
+    Sbd => ("SBD", 2),
+    Scr => ("SCR", 2),
+    Sdg => ("SDG", 2),
+    Sek => ("SEK", 2),
+    Sgd => ("SGD", 2),
+    Shp => ("SHP", 2),
+    Sle => ("SLE", 2),
+    Sos => ("SOS", 2),
+    Srd => ("SRD", 2),
+    Ssp => ("SSP", 2),
+    Stn => ("STN", 2),
+    Svc => ("SVC", 2),
+    Syp => ("SYP", 2),
+    Szl => ("SZL", 2),
+    Thb => ("THB", 2),
+    Tjs => ("TJS", 2),
+    Tmt => ("TMT", 2),
+    Tnd => ("TND", 3),
+    Top => ("TOP", 2),
+    Try => ("TRY", 2),
+    Ttd => ("TTD", 2),
+    Twd => ("TWD", 2),
+    Tzs => ("TZS", 2),
+    Uah => ("UAH", 2),
+    Ugx => ("UGX", 0),
+    Usd => ("USD", 2),
+    Uyi => ("UYI", 0),
+    Uyu => ("UYU", 2),
+    Uyw => ("UYW", 4),
+    Uzs => ("UZS", 2),
+    Ved => ("VED", 2),
+    Ves => ("VES", 2),
+    Vnd => ("VND", 0),
+    Vuv => ("VUV", 0),
+    Wst => ("WST", 2),
+    Xaf => ("XAF", 0),
+    Xcd => ("XCD", 2),
+    Xcg => ("XCG", 2),
+    Xof => ("XOF", 0),
+    Xpf => ("XPF", 0),
+    Yer => ("YER", 2),
+    Zar => ("ZAR", 2),
+    Zmw => ("ZMW", 2),
+    Zwg => ("ZWG", 2),
+}
+
+impl fmt::Display for IsoCurrency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl Serialize for IsoCurrency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for IsoCurrency {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let code = String::deserialize(deserializer)?;
+        Ok(IsoCurrency::from_code(&code))
+    }
+}
+
+/// The currency of an amount. Plaid reports exactly one of `iso_currency_code`
+/// and `unofficial_currency_code`; the latter covers crypto and similar.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Currency {
+    Iso(IsoCurrency),
+    Unofficial(String),
+}
+
+impl Currency {
+    pub fn code(&self) -> &str {
+        match self {
+            Currency::Iso(currency) => currency.code(),
+            Currency::Unofficial(code) => code,
+        }
+    }
+
+    /// Decimal places for known ISO currencies; unknown for unofficial ones
+    pub fn minor_units(&self) -> Option<u32> {
+        match self {
+            Currency::Iso(currency) => currency.minor_units(),
+            Currency::Unofficial(_) => None,
+        }
+    }
+}
+
+impl From<IsoCurrency> for Currency {
+    fn from(currency: IsoCurrency) -> Self {
+        Currency::Iso(currency)
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// (De)serializes an `Option<Currency>` as Plaid's flattened
+/// `iso_currency_code` / `unofficial_currency_code` pair
+pub(crate) mod currency_codes {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{Currency, IsoCurrency};
+
+    #[derive(Serialize, Deserialize)]
+    struct CurrencyCodes {
+        #[serde(default)]
+        iso_currency_code: Option<String>,
+        #[serde(default)]
+        unofficial_currency_code: Option<String>,
+    }
+
+    pub fn serialize<S>(currency: &Option<Currency>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let codes = match currency {
+            Some(Currency::Iso(currency)) => CurrencyCodes {
+                iso_currency_code: Some(currency.code().to_string()),
+                unofficial_currency_code: None,
+            },
+            Some(Currency::Unofficial(code)) => CurrencyCodes {
+                iso_currency_code: None,
+                unofficial_currency_code: Some(code.clone()),
+            },
+            None => CurrencyCodes {
+                iso_currency_code: None,
+                unofficial_currency_code: None,
+            },
+        };
+        codes.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Currency>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let codes = CurrencyCodes::deserialize(deserializer)?;
+        Ok(
+            match (codes.iso_currency_code, codes.unofficial_currency_code) {
+                (Some(code), _) => Some(Currency::Iso(IsoCurrency::from_code(&code))),
+                (None, Some(code)) => Some(Currency::Unofficial(code)),
+                (None, None) => None,
+            },
+        )
+    }
+}
+
+/// (De)serializes an [`Amount`] as the decimal string with two digits of
+/// precision used by the Transfer API, e.g. `"12.34"`. Amounts with a
+/// fraction of a cent fail to serialize rather than move a different amount.
+pub(crate) mod decimal_string {
+    use serde::{ser::Error, Deserialize, Deserializer, Serializer};
+
+    use super::Amount;
+
+    pub fn serialize<S>(amount: &Amount, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // trailing zeros, as in `12.340`, are still a whole number of cents
+        if amount.as_decimal().normalize().scale() > 2 {
+            return Err(S::Error::custom(format!(
+                "transfer amount {amount} has more than two decimal places"
+            )));
+        }
+        serializer.serialize_str(&format!("{:.2}", amount.round_dp(2).as_decimal()))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Amount, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Amount::deserialize(deserializer)
+    }
+
+    pub(crate) mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        use super::Amount;
+
+        pub fn serialize<S>(amount: &Option<Amount>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match amount {
+                Some(amount) => super::serialize(amount, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Amount>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Option::<Amount>::deserialize(deserializer)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unseen_product_decodes() {
+        let products: Vec<Product> =
+            serde_json::from_str(r#"["auth", "some_new_product"]"#).unwrap();
+        assert_eq!(
+            products,
+            vec![Product::Auth, Product::Unknown("some_new_product".into())]
+        );
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct TransferAmount {
+        #[serde(with = "decimal_string")]
+        amount: Amount,
+    }
+
+    #[test]
+    fn transfer_amounts_are_decimal_strings() {
+        let amount = TransferAmount {
+            amount: "12.3".parse().unwrap(),
+        };
+        assert_eq!(
+            serde_json::to_string(&amount).unwrap(),
+            r#"{"amount":"12.30"}"#
+        );
+
+        let decoded: TransferAmount = serde_json::from_str(r#"{"amount":"12.30"}"#).unwrap();
+        assert_eq!(decoded.amount, "12.30".parse().unwrap());
+    }
+
+    #[test]
+    fn transfer_amounts_round_trip() {
+        let decoded: TransferAmount = serde_json::from_str(r#"{"amount":"12.34"}"#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&decoded).unwrap(),
+            r#"{"amount":"12.34"}"#
+        );
+    }
+
+    #[test]
+    fn sub_cent_transfer_amounts_are_rejected() {
+        for amount in ["12.345", "12.349", "0.005"] {
+            let amount = TransferAmount {
+                amount: amount.parse().unwrap(),
+            };
+            assert!(serde_json::to_string(&amount).is_err(), "{amount:?}");
+        }
+
+        let amount = TransferAmount {
+            amount: "12.340".parse().unwrap(),
+        };
+        assert_eq!(
+            serde_json::to_string(&amount).unwrap(),
+            r#"{"amount":"12.34"}"#
+        );
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Priced {
+        #[serde(flatten, with = "currency_codes")]
+        currency: Option<Currency>,
+    }
+
+    #[test]
+    fn unseen_iso_currency_stays_iso() {
+        let json = r#"{"iso_currency_code":"XYZ","unofficial_currency_code":null}"#;
+        let priced: Priced = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            priced.currency,
+            Some(Currency::Iso(IsoCurrency::Unknown("XYZ".into())))
+        );
+        assert_eq!(serde_json::to_string(&priced).unwrap(), json);
+    }
 }
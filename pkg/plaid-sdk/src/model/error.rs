@@ -23,35 +23,527 @@ pub enum PlaidErrorType {
     TransactionError,
     TransactionsError,
     TransferError,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+/// What a caller should do about a failed request
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Remediation {
+    /// Send the user through Link in update mode
+    Relink,
+    /// The same request may succeed later without changes
+    RetryLater,
+    /// The request or the client configuration needs to change
+    FixInput,
+}
+
+/// Defines the codes Plaid documents under one error type, each with its
+/// wire value and the remediation it calls for
+macro_rules! error_codes {
+    ($(#[$meta:meta])* $name:ident {
+        $($variant:ident => ($code:literal, $remediation:ident),)*
+    }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum $name {
+            $($variant,)*
+            Unknown(String),
+        }
+
+        impl $name {
+            pub fn from_code(code: &str) -> Self {
+                match code {
+                    $($code => $name::$variant,)*
+                    _ => $name::Unknown(code.to_string()),
+                }
+            }
+
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $code,)*
+                    $name::Unknown(code) => code,
+                }
+            }
+
+            pub fn suggested_remediation(&self) -> Option<Remediation> {
+                match self {
+                    $($name::$variant => Some(Remediation::$remediation),)*
+                    $name::Unknown(_) => None,
+                }
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                String::deserialize(deserializer).map(|code| $name::from_code(&code))
+            }
+        }
+    };
+}
+
+error_codes! {
+    /// Codes of the `API_ERROR` error type
+    ApiErrorCode {
+        InternalServerError => ("INTERNAL_SERVER_ERROR", RetryLater),
+        PlannedMaintenance => ("PLANNED_MAINTENANCE", RetryLater),
+    }
+}
+
+error_codes! {
+    /// Codes of the `ASSET_REPORT_ERROR` error type
+    AssetReportErrorCode {
+        AssetProductNotEnabled => ("ASSET_PRODUCT_NOT_ENABLED", FixInput),
+        AssetReportGenerationFailed => ("ASSET_REPORT_GENERATION_FAILED", RetryLater),
+        DataQualityCheckFailed => ("DATA_QUALITY_CHECK_FAILED", FixInput),
+        DataUnavailable => ("DATA_UNAVAILABLE", Relink),
+        InsightsNotEnabled => ("INSIGHTS_NOT_ENABLED", FixInput),
+        InsightsPreviouslyNotEnabled => ("INSIGHTS_PREVIOUSLY_NOT_ENABLED", FixInput),
+        InvalidParent => ("INVALID_PARENT", FixInput),
+        ProductNotEnabled => ("PRODUCT_NOT_ENABLED", FixInput),
+        ProductNotReady => ("PRODUCT_NOT_READY", RetryLater),
+    }
+}
+
+error_codes! {
+    /// Codes of the `BANK_TRANSFER_ERROR` error type
+    BankTransferErrorCode {
+        BankTransferAccountBlocked => ("BANK_TRANSFER_ACCOUNT_BLOCKED", FixInput),
+        BankTransferInsufficientFunds => ("BANK_TRANSFER_INSUFFICIENT_FUNDS", FixInput),
+        BankTransferInvalidOriginationAccount => ("BANK_TRANSFER_INVALID_ORIGINATION_ACCOUNT", FixInput),
+        BankTransferLimitExceeded => ("BANK_TRANSFER_LIMIT_EXCEEDED", FixInput),
+        BankTransferMissingOriginationAccount => ("BANK_TRANSFER_MISSING_ORIGINATION_ACCOUNT", FixInput),
+        BankTransferNotCancellable => ("BANK_TRANSFER_NOT_CANCELLABLE", FixInput),
+        BankTransferUnsupportedAccountType => ("BANK_TRANSFER_UNSUPPORTED_ACCOUNT_TYPE", FixInput),
+        BankTransferUnsupportedEnvironment => ("BANK_TRANSFER_UNSUPPORTED_ENVIRONMENT", FixInput),
+    }
+}
+
+error_codes! {
+    /// Codes of the `INCOME_VERIFICATION_ERROR` error type
+    IncomeVerificationErrorCode {
+        EmploymentNotFound => ("EMPLOYMENT_NOT_FOUND", FixInput),
+        IncomeVerificationDocumentNotFound => ("INCOME_VERIFICATION_DOCUMENT_NOT_FOUND", FixInput),
+        IncomeVerificationFailed => ("INCOME_VERIFICATION_FAILED", RetryLater),
+        IncomeVerificationNotFound => ("INCOME_VERIFICATION_NOT_FOUND", FixInput),
+        IncomeVerificationUploadError => ("INCOME_VERIFICATION_UPLOAD_ERROR", RetryLater),
+        ProductNotEnabled => ("PRODUCT_NOT_ENABLED", FixInput),
+        ProductNotReady => ("PRODUCT_NOT_READY", RetryLater),
+        VerificationStatusPendingApproval => ("VERIFICATION_STATUS_PENDING_APPROVAL", RetryLater),
+    }
+}
+
+error_codes! {
+    /// Codes of the `INSTITUTION_ERROR` error type
+    InstitutionErrorCode {
+        InstitutionDown => ("INSTITUTION_DOWN", RetryLater),
+        InstitutionNoLongerSupported => ("INSTITUTION_NO_LONGER_SUPPORTED", Relink),
+        InstitutionNotAvailable => ("INSTITUTION_NOT_AVAILABLE", RetryLater),
+        InstitutionNotResponding => ("INSTITUTION_NOT_RESPONDING", RetryLater),
+        InstitutionRegistrationRequired => ("INSTITUTION_REGISTRATION_REQUIRED", FixInput),
+        UnauthorizedInstitution => ("UNAUTHORIZED_INSTITUTION", FixInput),
+    }
+}
+
+error_codes! {
+    /// Codes of the `INVALID_INPUT` error type
+    InvalidInputCode {
+        AdditionalConsentRequired => ("ADDITIONAL_CONSENT_REQUIRED", Relink),
+        DirectIntegrationNotEnabled => ("DIRECT_INTEGRATION_NOT_ENABLED", FixInput),
+        IncorrectDepositVerification => ("INCORRECT_DEPOSIT_VERIFICATION", FixInput),
+        InvalidAccessToken => ("INVALID_ACCESS_TOKEN", FixInput),
+        InvalidAccountId => ("INVALID_ACCOUNT_ID", FixInput),
+        InvalidApiKeys => ("INVALID_API_KEYS", FixInput),
+        InvalidAuditCopyToken => ("INVALID_AUDIT_COPY_TOKEN", FixInput),
+        InvalidClientId => ("INVALID_CLIENT_ID", FixInput),
+        InvalidInstitution => ("INVALID_INSTITUTION", FixInput),
+        InvalidLinkCustomization => ("INVALID_LINK_CUSTOMIZATION", FixInput),
+        InvalidLinkToken => ("INVALID_LINK_TOKEN", FixInput),
+        InvalidProcessorCount => ("INVALID_PROCESSOR_COUNT", FixInput),
+        InvalidProcessorToken => ("INVALID_PROCESSOR_TOKEN", FixInput),
+        InvalidProduct => ("INVALID_PRODUCT", FixInput),
+        InvalidProducts => ("INVALID_PRODUCTS", FixInput),
+        InvalidPublicToken => ("INVALID_PUBLIC_TOKEN", FixInput),
+        InvalidStripeAccount => ("INVALID_STRIPE_ACCOUNT", FixInput),
+        InvalidUserToken => ("INVALID_USER_TOKEN", FixInput),
+        InvalidWebhookVerificationKeyId => ("INVALID_WEBHOOK_VERIFICATION_KEY_ID", FixInput),
+        TooManyVerificationAttempts => ("TOO_MANY_VERIFICATION_ATTEMPTS", Relink),
+        UnauthorizedEnvironment => ("UNAUTHORIZED_ENVIRONMENT", FixInput),
+        UnauthorizedRouteAccess => ("UNAUTHORIZED_ROUTE_ACCESS", FixInput),
+        UserPermissionRevoked => ("USER_PERMISSION_REVOKED", Relink),
+    }
+}
+
+error_codes! {
+    /// Codes of the `INVALID_REQUEST` error type
+    InvalidRequestCode {
+        IncompatibleApiVersion => ("INCOMPATIBLE_API_VERSION", FixInput),
+        InvalidAccountNumber => ("INVALID_ACCOUNT_NUMBER", FixInput),
+        InvalidBody => ("INVALID_BODY", FixInput),
+        InvalidField => ("INVALID_FIELD", FixInput),
+        InvalidHeaders => ("INVALID_HEADERS", FixInput),
+        MissingFields => ("MISSING_FIELDS", FixInput),
+        NoLongerAvailable => ("NO_LONGER_AVAILABLE", FixInput),
+        NotFound => ("NOT_FOUND", FixInput),
+        SandboxOnly => ("SANDBOX_ONLY", FixInput),
+        UnknownFields => ("UNKNOWN_FIELDS", FixInput),
+    }
+}
+
+error_codes! {
+    /// Codes of the `INVALID_RESULT` error type
+    InvalidResultCode {
+        LastUpdatedDatetimeOutOfRange => ("LAST_UPDATED_DATETIME_OUT_OF_RANGE", FixInput),
+        PlaidDirectItemImportReturnedInvalidMfa => ("PLAID_DIRECT_ITEM_IMPORT_RETURNED_INVALID_MFA", Relink),
+    }
+}
+
+error_codes! {
+    /// Codes of the `ITEM_ERROR` error type
+    ItemErrorCode {
+        AccessNotGranted => ("ACCESS_NOT_GRANTED", Relink),
+        InstantMatchFailed => ("INSTANT_MATCH_FAILED", Relink),
+        InsufficientCredentials => ("INSUFFICIENT_CREDENTIALS", Relink),
+        InvalidCredentials => ("INVALID_CREDENTIALS", Relink),
+        InvalidMfa => ("INVALID_MFA", Relink),
+        InvalidOtp => ("INVALID_OTP", Relink),
+        InvalidPhoneNumber => ("INVALID_PHONE_NUMBER", FixInput),
+        InvalidSendMethod => ("INVALID_SEND_METHOD", Relink),
+        InvalidUpdatedUsername => ("INVALID_UPDATED_USERNAME", Relink),
+        ItemConcurrentlyDeleted => ("ITEM_CONCURRENTLY_DELETED", FixInput),
+        ItemLocked => ("ITEM_LOCKED", Relink),
+        ItemLoginRequired => ("ITEM_LOGIN_REQUIRED", Relink),
+        ItemNoError => ("ITEM_NO_ERROR", FixInput),
+        ItemNotSupported => ("ITEM_NOT_SUPPORTED", Relink),
+        MfaNotSupported => ("MFA_NOT_SUPPORTED", Relink),
+        NoAccounts => ("NO_ACCOUNTS", Relink),
+        NoAuthAccounts => ("NO_AUTH_ACCOUNTS", Relink),
+        NoInvestmentAccounts => ("NO_INVESTMENT_ACCOUNTS", Relink),
+        NoInvestmentAuthAccounts => ("NO_INVESTMENT_AUTH_ACCOUNTS", Relink),
+        NoLiabilityAccounts => ("NO_LIABILITY_ACCOUNTS", Relink),
+        PasswordResetRequired => ("PASSWORD_RESET_REQUIRED", Relink),
+        PendingExpiration => ("PENDING_EXPIRATION", Relink),
+        ProductNotEnabled => ("PRODUCT_NOT_ENABLED", FixInput),
+        ProductNotReady => ("PRODUCT_NOT_READY", RetryLater),
+        ProductUnavailable => ("PRODUCT_UNAVAILABLE", FixInput),
+        ProductsNotSupported => ("PRODUCTS_NOT_SUPPORTED", FixInput),
+        UserInputTimeout => ("USER_INPUT_TIMEOUT", Relink),
+        UserSetupRequired => ("USER_SETUP_REQUIRED", Relink),
+    }
+}
+
+error_codes! {
+    /// Codes of the `OAUTH_ERROR` error type
+    OauthErrorCode {
+        IncorrectLinkToken => ("INCORRECT_LINK_TOKEN", FixInput),
+        IncorrectOauthNonce => ("INCORRECT_OAUTH_NONCE", FixInput),
+        OauthStateIdAlreadyProcessed => ("OAUTH_STATE_ID_ALREADY_PROCESSED", FixInput),
+        OauthStateIdNotFound => ("OAUTH_STATE_ID_NOT_FOUND", Relink),
+    }
+}
+
+error_codes! {
+    /// Codes of the `PARTNER_ERROR` error type
+    PartnerErrorCode {
+        AssetsUnderManagementRequired => ("ASSETS_UNDER_MANAGEMENT_REQUIRED", FixInput),
+        ContactInformationRequired => ("CONTACT_INFORMATION_REQUIRED", FixInput),
+        CustomerAlreadyCreated => ("CUSTOMER_ALREADY_CREATED", FixInput),
+        CustomerAlreadyEnabled => ("CUSTOMER_ALREADY_ENABLED", FixInput),
+        CustomerNotFound => ("CUSTOMER_NOT_FOUND", FixInput),
+        CustomerNotReadyForEnablement => ("CUSTOMER_NOT_READY_FOR_ENABLEMENT", RetryLater),
+        CustomerRemovalNotAllowed => ("CUSTOMER_REMOVAL_NOT_ALLOWED", FixInput),
+        FlowdownNotComplete => ("FLOWDOWN_NOT_COMPLETE", FixInput),
+        InvalidLogo => ("INVALID_LOGO", FixInput),
+        LogoRequired => ("LOGO_REQUIRED", FixInput),
+        QuestionnaireNotComplete => ("QUESTIONNAIRE_NOT_COMPLETE", FixInput),
+    }
+}
+
+error_codes! {
+    /// Codes of the `PAYMENT_ERROR` error type
+    PaymentErrorCode {
+        PaymentBlocked => ("PAYMENT_BLOCKED", FixInput),
+        PaymentCancelled => ("PAYMENT_CANCELLED", FixInput),
+        PaymentConsentCancelled => ("PAYMENT_CONSENT_CANCELLED", Relink),
+        PaymentConsentInvalidConstraints => ("PAYMENT_CONSENT_INVALID_CONSTRAINTS", FixInput),
+        PaymentInsufficientFunds => ("PAYMENT_INSUFFICIENT_FUNDS", FixInput),
+        PaymentInvalidRecipient => ("PAYMENT_INVALID_RECIPIENT", FixInput),
+        PaymentInvalidReference => ("PAYMENT_INVALID_REFERENCE", FixInput),
+        PaymentInvalidSchedule => ("PAYMENT_INVALID_SCHEDULE", FixInput),
+        PaymentRejected => ("PAYMENT_REJECTED", FixInput),
+        PaymentSchemeNotSupported => ("PAYMENT_SCHEME_NOT_SUPPORTED", FixInput),
+    }
+}
+
+error_codes! {
+    /// Codes of the `RATE_LIMIT_EXCEEDED` error type
+    RateLimitExceededCode {
+        AccountsBalanceGetLimit => ("ACCOUNTS_BALANCE_GET_LIMIT", RetryLater),
+        AccountsLimit => ("ACCOUNTS_LIMIT", RetryLater),
+        AuthLimit => ("AUTH_LIMIT", RetryLater),
+        BalanceLimit => ("BALANCE_LIMIT", RetryLater),
+        CreditsExhausted => ("CREDITS_EXHAUSTED", FixInput),
+        IdentityLimit => ("IDENTITY_LIMIT", RetryLater),
+        InstitutionsGetByIdLimit => ("INSTITUTIONS_GET_BY_ID_LIMIT", RetryLater),
+        InstitutionsGetLimit => ("INSTITUTIONS_GET_LIMIT", RetryLater),
+        InvestmentHoldingsGetLimit => ("INVESTMENT_HOLDINGS_GET_LIMIT", RetryLater),
+        InvestmentTransactionsLimit => ("INVESTMENT_TRANSACTIONS_LIMIT", RetryLater),
+        ItemGetLimit => ("ITEM_GET_LIMIT", RetryLater),
+        RateLimit => ("RATE_LIMIT", RetryLater),
+        TransactionsLimit => ("TRANSACTIONS_LIMIT", RetryLater),
+        TransactionsSyncLimit => ("TRANSACTIONS_SYNC_LIMIT", RetryLater),
+    }
+}
+
+error_codes! {
+    /// Codes of the `RECAPTCHA_ERROR` error type
+    RecaptchaErrorCode {
+        RecaptchaBad => ("RECAPTCHA_BAD", Relink),
+        RecaptchaRequired => ("RECAPTCHA_REQUIRED", Relink),
+    }
+}
+
+error_codes! {
+    /// Codes of the `SANDBOX_ERROR` error type
+    SandboxErrorCode {
+        SandboxProductNotEnabled => ("SANDBOX_PRODUCT_NOT_ENABLED", FixInput),
+        SandboxTransferEventTransitionInvalid => ("SANDBOX_TRANSFER_EVENT_TRANSITION_INVALID", FixInput),
+        SandboxWebhookInvalid => ("SANDBOX_WEBHOOK_INVALID", FixInput),
+    }
+}
+
+error_codes! {
+    /// Codes of the `TRANSACTION_ERROR` error type
+    TransactionErrorCode {
+        TransactionAmountExceeded => ("TRANSACTION_AMOUNT_EXCEEDED", FixInput),
+        TransactionBacsInvalid => ("TRANSACTION_BACS_INVALID", FixInput),
+        TransactionCurrencyMismatch => ("TRANSACTION_CURRENCY_MISMATCH", FixInput),
+        TransactionExecutionFailed => ("TRANSACTION_EXECUTION_FAILED", RetryLater),
+        TransactionFastPayDisabled => ("TRANSACTION_FAST_PAY_DISABLED", FixInput),
+        TransactionIbanInvalid => ("TRANSACTION_IBAN_INVALID", FixInput),
+        TransactionInsufficientFunds => ("TRANSACTION_INSUFFICIENT_FUNDS", FixInput),
+        TransactionOnSameAccount => ("TRANSACTION_ON_SAME_ACCOUNT", FixInput),
+    }
+}
+
+error_codes! {
+    /// Codes of the `TRANSACTIONS_ERROR` error type
+    TransactionsErrorCode {
+        TransactionsSyncMutationDuringPagination => ("TRANSACTIONS_SYNC_MUTATION_DURING_PAGINATION", RetryLater),
+    }
+}
+
+error_codes! {
+    /// Codes of the `TRANSFER_ERROR` error type
+    TransferErrorCode {
+        IncompleteCustomerOnboarding => ("INCOMPLETE_CUSTOMER_ONBOARDING", FixInput),
+        TransferAccountBlocked => ("TRANSFER_ACCOUNT_BLOCKED", FixInput),
+        TransferForbiddenAchClass => ("TRANSFER_FORBIDDEN_ACH_CLASS", FixInput),
+        TransferInvalidOriginationAccount => ("TRANSFER_INVALID_ORIGINATION_ACCOUNT", FixInput),
+        TransferLimitExceeded => ("TRANSFER_LIMIT_EXCEEDED", FixInput),
+        TransferMissingOriginationAccount => ("TRANSFER_MISSING_ORIGINATION_ACCOUNT", FixInput),
+        TransferNetworkLimitExceeded => ("TRANSFER_NETWORK_LIMIT_EXCEEDED", RetryLater),
+        TransferNotCancellable => ("TRANSFER_NOT_CANCELLABLE", FixInput),
+        TransferOriginatorNotFound => ("TRANSFER_ORIGINATOR_NOT_FOUND", FixInput),
+        TransferUnsupportedAccountType => ("TRANSFER_UNSUPPORTED_ACCOUNT_TYPE", FixInput),
+        UnauthorizedAccess => ("UNAUTHORIZED_ACCESS", FixInput),
+    }
+}
+
+/// Every known error code, nested under the error type Plaid reports it with.
+/// The same code (e.g. `PRODUCT_NOT_READY`) can appear under several types.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PlaidErrorCode {
-    AdditionalConsentRequired,
-    DirectIntegrationnotEnabled,
-    IncorrectDepositVerification,
-    InvalidAccessToken,
-    InvalidAccountId,
-    InvalidApiKeys,
-    InvalidAuditCopyToken,
-    InvalidClientId,
-    InvalidField,
-    InvalidInstitution,
-    InvalidLinkCustomization,
-    InvalidProcessorCount,
-    InvalidProduct,
-    InvalidProducts,
-    InvalidPublicToken,
-    InvalidStripeAccount,
-    InvalidUserToken,
-    InvalidWebhookVerificationKeyId,
-    ProductUnavailable,
-    TooManyVerificationAttempts,
-    UnauthorizedEnvironment,
-    UnauthorizedRouteAccess,
-    UnknownFields,
-    UserPermissionRevoked,
+    ApiError(ApiErrorCode),
+    AssetReportError(AssetReportErrorCode),
+    BankTransferError(BankTransferErrorCode),
+    IncomeVerificationError(IncomeVerificationErrorCode),
+    InstitutionError(InstitutionErrorCode),
+    InvalidInput(InvalidInputCode),
+    InvalidRequest(InvalidRequestCode),
+    InvalidResult(InvalidResultCode),
+    ItemError(ItemErrorCode),
+    OauthError(OauthErrorCode),
+    PartnerError(PartnerErrorCode),
+    PaymentError(PaymentErrorCode),
+    RateLimitExceeded(RateLimitExceededCode),
+    RecaptchaError(RecaptchaErrorCode),
+    SandboxError(SandboxErrorCode),
+    TransactionError(TransactionErrorCode),
+    TransactionsError(TransactionsErrorCode),
+    TransferError(TransferErrorCode),
+    /// A code under an error type without a catalog here, such as
+    /// `MICRODEPOSITS_ERROR`, or one this SDK has not seen yet
+    Unknown(String),
+}
+
+impl PlaidErrorCode {
+    /// Resolves `error_code` within the catalog of its `error_type`
+    pub fn from_parts(error_type: &PlaidErrorType, code: &str) -> Self {
+        match error_type {
+            PlaidErrorType::ApiError => PlaidErrorCode::ApiError(ApiErrorCode::from_code(code)),
+            PlaidErrorType::AssetReportError => {
+                PlaidErrorCode::AssetReportError(AssetReportErrorCode::from_code(code))
+            }
+            PlaidErrorType::BankTransferError => {
+                PlaidErrorCode::BankTransferError(BankTransferErrorCode::from_code(code))
+            }
+            PlaidErrorType::IncomeVerificationError => PlaidErrorCode::IncomeVerificationError(
+                IncomeVerificationErrorCode::from_code(code),
+            ),
+            PlaidErrorType::InstitutionError => {
+                PlaidErrorCode::InstitutionError(InstitutionErrorCode::from_code(code))
+            }
+            PlaidErrorType::InvalidInput => {
+                PlaidErrorCode::InvalidInput(InvalidInputCode::from_code(code))
+            }
+            PlaidErrorType::InvalidRequest => {
+                PlaidErrorCode::InvalidRequest(InvalidRequestCode::from_code(code))
+            }
+            PlaidErrorType::InvalidResult => {
+                PlaidErrorCode::InvalidResult(InvalidResultCode::from_code(code))
+            }
+            PlaidErrorType::ItemError => PlaidErrorCode::ItemError(ItemErrorCode::from_code(code)),
+            PlaidErrorType::OauthError => {
+                PlaidErrorCode::OauthError(OauthErrorCode::from_code(code))
+            }
+            PlaidErrorType::PartnerError => {
+                PlaidErrorCode::PartnerError(PartnerErrorCode::from_code(code))
+            }
+            PlaidErrorType::PaymentError => {
+                PlaidErrorCode::PaymentError(PaymentErrorCode::from_code(code))
+            }
+            PlaidErrorType::RateLimitExceeded => {
+                PlaidErrorCode::RateLimitExceeded(RateLimitExceededCode::from_code(code))
+            }
+            PlaidErrorType::RecaptchaError => {
+                PlaidErrorCode::RecaptchaError(RecaptchaErrorCode::from_code(code))
+            }
+            PlaidErrorType::SandboxError => {
+                PlaidErrorCode::SandboxError(SandboxErrorCode::from_code(code))
+            }
+            PlaidErrorType::TransactionError => {
+                PlaidErrorCode::TransactionError(TransactionErrorCode::from_code(code))
+            }
+            PlaidErrorType::TransactionsError => {
+                PlaidErrorCode::TransactionsError(TransactionsErrorCode::from_code(code))
+            }
+            PlaidErrorType::TransferError => {
+                PlaidErrorCode::TransferError(TransferErrorCode::from_code(code))
+            }
+            _ => PlaidErrorCode::from_code(code),
+        }
+    }
+
+    /// Resolves a code without knowing its type, taking the first catalog
+    /// that lists it
+    pub fn from_code(code: &str) -> Self {
+        const CATALOGS: &[PlaidErrorType] = &[
+            PlaidErrorType::ApiError,
+            PlaidErrorType::AssetReportError,
+            PlaidErrorType::BankTransferError,
+            PlaidErrorType::IncomeVerificationError,
+            PlaidErrorType::InstitutionError,
+            PlaidErrorType::InvalidInput,
+            PlaidErrorType::InvalidRequest,
+            PlaidErrorType::InvalidResult,
+            PlaidErrorType::ItemError,
+            PlaidErrorType::OauthError,
+            PlaidErrorType::PartnerError,
+            PlaidErrorType::PaymentError,
+            PlaidErrorType::RateLimitExceeded,
+            PlaidErrorType::RecaptchaError,
+            PlaidErrorType::SandboxError,
+            PlaidErrorType::TransactionError,
+            PlaidErrorType::TransactionsError,
+            PlaidErrorType::TransferError,
+        ];
+
+        CATALOGS
+            .iter()
+            .map(|error_type| PlaidErrorCode::from_parts(error_type, code))
+            .find(|known| known.suggested_remediation().is_some())
+            .unwrap_or_else(|| PlaidErrorCode::Unknown(code.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            PlaidErrorCode::ApiError(code) => code.as_str(),
+            PlaidErrorCode::AssetReportError(code) => code.as_str(),
+            PlaidErrorCode::BankTransferError(code) => code.as_str(),
+            PlaidErrorCode::IncomeVerificationError(code) => code.as_str(),
+            PlaidErrorCode::InstitutionError(code) => code.as_str(),
+            PlaidErrorCode::InvalidInput(code) => code.as_str(),
+            PlaidErrorCode::InvalidRequest(code) => code.as_str(),
+            PlaidErrorCode::InvalidResult(code) => code.as_str(),
+            PlaidErrorCode::ItemError(code) => code.as_str(),
+            PlaidErrorCode::OauthError(code) => code.as_str(),
+            PlaidErrorCode::PartnerError(code) => code.as_str(),
+            PlaidErrorCode::PaymentError(code) => code.as_str(),
+            PlaidErrorCode::RateLimitExceeded(code) => code.as_str(),
+            PlaidErrorCode::RecaptchaError(code) => code.as_str(),
+            PlaidErrorCode::SandboxError(code) => code.as_str(),
+            PlaidErrorCode::TransactionError(code) => code.as_str(),
+            PlaidErrorCode::TransactionsError(code) => code.as_str(),
+            PlaidErrorCode::TransferError(code) => code.as_str(),
+            PlaidErrorCode::Unknown(code) => code,
+        }
+    }
+
+    /// `None` for codes this SDK does not know
+    pub fn suggested_remediation(&self) -> Option<Remediation> {
+        match self {
+            PlaidErrorCode::ApiError(code) => code.suggested_remediation(),
+            PlaidErrorCode::AssetReportError(code) => code.suggested_remediation(),
+            PlaidErrorCode::BankTransferError(code) => code.suggested_remediation(),
+            PlaidErrorCode::IncomeVerificationError(code) => code.suggested_remediation(),
+            PlaidErrorCode::InstitutionError(code) => code.suggested_remediation(),
+            PlaidErrorCode::InvalidInput(code) => code.suggested_remediation(),
+            PlaidErrorCode::InvalidRequest(code) => code.suggested_remediation(),
+            PlaidErrorCode::InvalidResult(code) => code.suggested_remediation(),
+            PlaidErrorCode::ItemError(code) => code.suggested_remediation(),
+            PlaidErrorCode::OauthError(code) => code.suggested_remediation(),
+            PlaidErrorCode::PartnerError(code) => code.suggested_remediation(),
+            PlaidErrorCode::PaymentError(code) => code.suggested_remediation(),
+            PlaidErrorCode::RateLimitExceeded(code) => code.suggested_remediation(),
+            PlaidErrorCode::RecaptchaError(code) => code.suggested_remediation(),
+            PlaidErrorCode::SandboxError(code) => code.suggested_remediation(),
+            PlaidErrorCode::TransactionError(code) => code.suggested_remediation(),
+            PlaidErrorCode::TransactionsError(code) => code.suggested_remediation(),
+            PlaidErrorCode::TransferError(code) => code.suggested_remediation(),
+            PlaidErrorCode::Unknown(_) => None,
+        }
+    }
+}
+
+impl Serialize for PlaidErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for PlaidErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|code| PlaidErrorCode::from_code(&code))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -60,9 +552,12 @@ pub enum PlaidErrorCodeReason {
     OauthInvalidToken,
     OauthConsentExpired,
     OauthRevokedToken,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "RawPlaidErrorResponse")]
 pub struct PlaidErrorResponse {
     pub causes: Option<Vec<Value>>,
     pub display_message: Option<String>,
@@ -75,3 +570,195 @@ pub struct PlaidErrorResponse {
     pub status: Option<i64>,
     pub suggested_action: Option<String>,
 }
+
+/// The error as sent by Plaid; `error_code` can only be resolved once
+/// `error_type` is known
+#[derive(Deserialize)]
+struct RawPlaidErrorResponse {
+    causes: Option<Vec<Value>>,
+    display_message: Option<String>,
+    documentation_url: String,
+    error_code: String,
+    error_code_reason: Option<PlaidErrorCodeReason>,
+    error_message: String,
+    error_type: PlaidErrorType,
+    request_id: String,
+    status: Option<i64>,
+    suggested_action: Option<String>,
+}
+
+impl From<RawPlaidErrorResponse> for PlaidErrorResponse {
+    fn from(raw: RawPlaidErrorResponse) -> Self {
+        Self {
+            causes: raw.causes,
+            display_message: raw.display_message,
+            documentation_url: raw.documentation_url,
+            error_code: PlaidErrorCode::from_parts(&raw.error_type, &raw.error_code),
+            error_code_reason: raw.error_code_reason,
+            error_message: raw.error_message,
+            error_type: raw.error_type,
+            request_id: raw.request_id,
+            status: raw.status,
+            suggested_action: raw.suggested_action,
+        }
+    }
+}
+
+impl PlaidErrorResponse {
+    /// Known OAuth failures (`error_code_reason`) always need the user to
+    /// relink; an unseen reason gives no remediation
+    pub fn suggested_remediation(&self) -> Option<Remediation> {
+        match &self.error_code_reason {
+            Some(
+                PlaidErrorCodeReason::OauthInvalidToken
+                | PlaidErrorCodeReason::OauthConsentExpired
+                | PlaidErrorCodeReason::OauthRevokedToken,
+            ) => Some(Remediation::Relink),
+            Some(PlaidErrorCodeReason::Unknown(_)) => None,
+            None => self.error_code.suggested_remediation(),
+        }
+    }
+
+    /// Whether the same request may succeed if sent again later
+    pub fn is_retryable(&self) -> bool {
+        match self.error_code.suggested_remediation() {
+            Some(remediation) => remediation == Remediation::RetryLater,
+            None => {
+                matches!(
+                    self.error_type,
+                    PlaidErrorType::ApiError | PlaidErrorType::RateLimitExceeded
+                ) || self.status.map_or(false, |status| status >= 500)
+            }
+        }
+    }
+
+    /// Whether the user has to go through Link (update mode) again before
+    /// the item can be used
+    pub fn requires_user_action(&self) -> bool {
+        self.suggested_remediation() == Some(Remediation::Relink)
+    }
+
+    pub fn is_item_login_required(&self) -> bool {
+        self.error_code == PlaidErrorCode::ItemError(ItemErrorCode::ItemLoginRequired)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unseen_error_type_and_code_decode() {
+        let failure: PlaidErrorResponse = serde_json::from_value(serde_json::json!({
+            "display_message": null,
+            "documentation_url": "https://plaid.com/docs/errors/",
+            "error_code": "SOME_NEW_CODE",
+            "error_code_reason": "SOME_NEW_REASON",
+            "error_message": "something new went wrong",
+            "error_type": "SOME_NEW_ERROR",
+            "request_id": "abc123",
+        }))
+        .unwrap();
+
+        assert_eq!(
+            failure.error_type,
+            PlaidErrorType::Unknown("SOME_NEW_ERROR".into())
+        );
+        assert_eq!(
+            failure.error_code,
+            PlaidErrorCode::Unknown("SOME_NEW_CODE".into())
+        );
+        assert_eq!(
+            failure.error_code_reason,
+            Some(PlaidErrorCodeReason::Unknown("SOME_NEW_REASON".into()))
+        );
+    }
+
+    #[test]
+    fn known_codes_still_decode_to_their_variant() {
+        let code: PlaidErrorCode = serde_json::from_str("\"ITEM_LOGIN_REQUIRED\"").unwrap();
+        assert_eq!(
+            code,
+            PlaidErrorCode::ItemError(ItemErrorCode::ItemLoginRequired)
+        );
+    }
+
+    #[test]
+    fn unknown_round_trips_raw_value() {
+        let code = PlaidErrorCode::Unknown("SOME_NEW_CODE".into());
+        assert_eq!(serde_json::to_string(&code).unwrap(), "\"SOME_NEW_CODE\"");
+    }
+
+    #[test]
+    fn code_is_resolved_within_its_error_type() {
+        let code =
+            PlaidErrorCode::from_parts(&PlaidErrorType::AssetReportError, "PRODUCT_NOT_READY");
+        assert_eq!(
+            code,
+            PlaidErrorCode::AssetReportError(AssetReportErrorCode::ProductNotReady)
+        );
+
+        let code = PlaidErrorCode::from_parts(&PlaidErrorType::InvalidInput, "SOME_NEW_CODE");
+        assert_eq!(
+            code,
+            PlaidErrorCode::InvalidInput(InvalidInputCode::Unknown("SOME_NEW_CODE".into()))
+        );
+        assert_eq!(code.suggested_remediation(), None);
+    }
+
+    #[test]
+    fn direct_integration_not_enabled_is_spelled_correctly() {
+        let code = PlaidErrorCode::from_code("DIRECT_INTEGRATION_NOT_ENABLED");
+        assert_eq!(
+            code,
+            PlaidErrorCode::InvalidInput(InvalidInputCode::DirectIntegrationNotEnabled)
+        );
+        assert_eq!(code.as_str(), "DIRECT_INTEGRATION_NOT_ENABLED");
+    }
+
+    #[test]
+    fn only_known_oauth_reasons_suggest_relinking() {
+        let failure = |reason: &str| -> PlaidErrorResponse {
+            serde_json::from_value(serde_json::json!({
+                "display_message": null,
+                "documentation_url": "https://plaid.com/docs/errors/",
+                "error_code": "ITEM_LOGIN_REQUIRED",
+                "error_code_reason": reason,
+                "error_message": "the login details of this item have changed",
+                "error_type": "ITEM_ERROR",
+                "request_id": "abc123",
+            }))
+            .unwrap()
+        };
+
+        let expired = failure("OAUTH_CONSENT_EXPIRED");
+        assert_eq!(expired.suggested_remediation(), Some(Remediation::Relink));
+        assert!(expired.requires_user_action());
+
+        let unseen = failure("SOME_NEW_REASON");
+        assert_eq!(unseen.suggested_remediation(), None);
+        assert!(!unseen.requires_user_action());
+    }
+
+    #[test]
+    fn remediation_follows_the_code() {
+        let failure: PlaidErrorResponse = serde_json::from_value(serde_json::json!({
+            "display_message": null,
+            "documentation_url": "https://plaid.com/docs/errors/",
+            "error_code": "ITEM_LOGIN_REQUIRED",
+            "error_message": "the login details of this item have changed",
+            "error_type": "ITEM_ERROR",
+            "request_id": "abc123",
+        }))
+        .unwrap();
+        assert_eq!(failure.suggested_remediation(), Some(Remediation::Relink));
+        assert!(failure.is_item_login_required());
+        assert!(!failure.is_retryable());
+
+        let code = PlaidErrorCode::from_parts(&PlaidErrorType::RateLimitExceeded, "RATE_LIMIT");
+        assert_eq!(code.suggested_remediation(), Some(Remediation::RetryLater));
+
+        let code = PlaidErrorCode::from_parts(&PlaidErrorType::InvalidRequest, "MISSING_FIELDS");
+        assert_eq!(code.suggested_remediation(), Some(Remediation::FixInput));
+    }
+}
@@ -0,0 +1,201 @@
+use serde::{Deserialize, Serialize};
+
+use super::secret::AccessToken;
+use super::{
+    accounts::{Account, AccountItem},
+    link::E164PhoneNumber,
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdentityGetRequestOptions {
+    pub account_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdentityGetRequest {
+    pub access_token: AccessToken,
+    pub options: Option<IdentityGetRequestOptions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PhoneNumberType {
+    Home,
+    Work,
+    Office,
+    Mobile,
+    Mobile1,
+    Other,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnerPhoneNumber {
+    /// As provided by the bank, e.g. `"+1 415-555-0123"` or `"1112223333"`
+    pub data: String,
+    pub primary: Option<bool>,
+    #[serde(rename = "type")]
+    pub type_: Option<PhoneNumberType>,
+}
+
+impl OwnerPhoneNumber {
+    /// `data` without separators, if it is in E.164 form or a bare 10-digit
+    /// (NANP) number, which gets a `+1` prefix. Anything else, such as a
+    /// local number without its country code, gives `None`.
+    pub fn e164(&self) -> Option<E164PhoneNumber> {
+        let digits: String = self
+            .data
+            .chars()
+            .filter(|c| c.is_ascii_digit() || *c == '+')
+            .collect();
+        let digits = match digits.len() {
+            10 if !digits.starts_with('+') => format!("+1{digits}"),
+            _ => digits,
+        };
+        E164PhoneNumber::new(&digits).ok()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmailType {
+    Primary,
+    Secondary,
+    Other,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnerEmail {
+    pub data: String,
+    pub primary: bool,
+    #[serde(rename = "type")]
+    pub type_: EmailType,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnerAddressData {
+    pub street: Option<String>,
+    pub city: Option<String>,
+    pub region: Option<String>,
+    pub postal_code: Option<String>,
+    pub country: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnerAddress {
+    pub data: OwnerAddressData,
+    pub primary: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Owner {
+    pub names: Vec<String>,
+    pub phone_numbers: Vec<OwnerPhoneNumber>,
+    pub emails: Vec<OwnerEmail>,
+    pub addresses: Vec<OwnerAddress>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountWithOwners {
+    #[serde(flatten)]
+    pub account: Account,
+    pub owners: Vec<Owner>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdentityGetResponse {
+    pub accounts: Vec<AccountWithOwners>,
+    pub item: AccountItem,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdentityMatchUser {
+    pub legal_name: Option<String>,
+    pub phone_number: Option<E164PhoneNumber>,
+    pub email_address: Option<String>,
+    pub address: Option<OwnerAddressData>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdentityMatchRequestOptions {
+    pub account_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdentityMatchRequest {
+    pub access_token: AccessToken,
+    pub user: IdentityMatchUser,
+    pub options: Option<IdentityMatchRequestOptions>,
+}
+
+/// Scores range from 0 to 100, higher being a closer match
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NameMatchScore {
+    pub score: Option<u8>,
+    pub is_first_name_or_last_name_match: Option<bool>,
+    pub is_nickname_match: Option<bool>,
+    pub is_business_name_detected: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchScore {
+    pub score: Option<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressMatchScore {
+    pub score: Option<u8>,
+    pub is_postal_code_match: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountWithMatchScores {
+    #[serde(flatten)]
+    pub account: Account,
+    pub legal_name: Option<NameMatchScore>,
+    pub phone_number: Option<MatchScore>,
+    pub email_address: Option<MatchScore>,
+    pub address: Option<AddressMatchScore>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdentityMatchResponse {
+    pub accounts: Vec<AccountWithMatchScores>,
+    pub item: AccountItem,
+    pub request_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bank_phone_numbers_decode_without_validation() {
+        let numbers: Vec<OwnerPhoneNumber> = serde_json::from_str(
+            r#"[
+                {"data": "+1 415-555-0123", "primary": true, "type": "home"},
+                {"data": "1112223333", "primary": false, "type": "work"},
+                {"data": "555-1234", "primary": false, "type": "mobile"}
+            ]"#,
+        )
+        .unwrap();
+
+        let e164: Vec<_> = numbers
+            .iter()
+            .map(|number| number.e164().map(|number| number.to_string()))
+            .collect();
+        assert_eq!(
+            e164,
+            vec![
+                Some("+14155550123".to_string()),
+                Some("+11112223333".to_string()),
+                None
+            ]
+        );
+        assert_eq!(numbers[2].data, "555-1234");
+    }
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+use super::{common::Product, link::CountryCode};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Institution {
+    pub institution_id: String,
+    pub name: String,
+    pub products: Vec<Product>,
+    pub country_codes: Vec<CountryCode>,
+    pub url: Option<String>,
+    /// Hexadecimal, e.g. `"#095aa6"`. Only set with `include_optional_metadata`.
+    pub primary_color: Option<String>,
+    /// Base64 encoded PNG. Only set with `include_optional_metadata`.
+    pub logo: Option<String>,
+    pub routing_numbers: Vec<String>,
+    pub dtc_numbers: Option<Vec<String>>,
+    pub oauth: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstitutionsGetRequestOptions {
+    pub products: Option<Vec<Product>>,
+    pub routing_numbers: Option<Vec<String>>,
+    pub oauth: Option<bool>,
+    pub include_optional_metadata: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InstitutionsGetRequest {
+    /// Between 1 and 500
+    pub count: u32,
+    pub offset: u32,
+    pub country_codes: Vec<CountryCode>,
+    pub options: Option<InstitutionsGetRequestOptions>,
+}
+
+/// Asks for the first page of 100 institutions, since Plaid rejects a `count` of 0
+impl Default for InstitutionsGetRequest {
+    fn default() -> Self {
+        Self {
+            count: 100,
+            offset: 0,
+            country_codes: Vec::new(),
+            options: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstitutionsGetResponse {
+    pub institutions: Vec<Institution>,
+    pub total: u32,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstitutionsSearchRequestOptions {
+    pub oauth: Option<bool>,
+    pub include_optional_metadata: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct InstitutionsSearchRequest {
+    pub query: String,
+    pub products: Option<Vec<Product>>,
+    pub country_codes: Vec<CountryCode>,
+    pub options: Option<InstitutionsSearchRequestOptions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstitutionsSearchResponse {
+    pub institutions: Vec<Institution>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstitutionsGetByIdRequestOptions {
+    pub include_optional_metadata: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct InstitutionsGetByIdRequest {
+    pub institution_id: String,
+    pub country_codes: Vec<CountryCode>,
+    pub options: Option<InstitutionsGetByIdRequestOptions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstitutionsGetByIdResponse {
+    pub institution: Institution,
+    pub request_id: String,
+}
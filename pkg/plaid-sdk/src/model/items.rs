@@ -1,15 +1,95 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::accounts::AccountItem;
+use super::secret::{AccessToken, PublicToken};
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ItemPublicTokenExchangeRequest {
-    pub client_id: String,
-    pub secret: String,
-    pub public_token: String,
+    pub public_token: PublicToken,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ItemPublicTokenExchangeResponse {
-    pub access_token: String,
+    pub access_token: AccessToken,
     pub item_id: String,
     pub request_id: String,
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ItemGetRequest {
+    pub access_token: AccessToken,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemStatusUpdate {
+    pub last_successful_update: Option<DateTime<Utc>>,
+    pub last_failed_update: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemStatusLastWebhook {
+    pub sent_at: Option<DateTime<Utc>>,
+    pub code_sent: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemStatus {
+    pub investments: Option<ItemStatusUpdate>,
+    pub transactions: Option<ItemStatusUpdate>,
+    pub last_webhook: Option<ItemStatusLastWebhook>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemGetResponse {
+    pub item: AccountItem,
+    pub status: Option<ItemStatus>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ItemRemoveReasonCode {
+    FraudFirstParty,
+    FraudFalseIdentity,
+    FraudAbuse,
+    FraudOther,
+    ConnectionIsNonFunctional,
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ItemRemoveRequest {
+    pub access_token: AccessToken,
+    pub reason_code: Option<ItemRemoveReasonCode>,
+    pub reason_note: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ItemRemoveResponse {
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ItemWebhookUpdateRequest {
+    pub access_token: AccessToken,
+    /// `None` removes the webhook from the item
+    pub webhook: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemWebhookUpdateResponse {
+    pub item: AccountItem,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ItemAccessTokenInvalidateRequest {
+    pub access_token: AccessToken,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ItemAccessTokenInvalidateResponse {
+    pub new_access_token: AccessToken,
+    pub request_id: String,
+}
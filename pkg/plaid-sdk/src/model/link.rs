@@ -1,4 +1,4 @@
-use std::{collections::HashMap, fmt};
+use std::fmt;
 
 use chrono::{DateTime, Utc};
 use regex::Regex;
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::{error::PlaidError, PlaidResult};
 
 use super::common::Product;
+use super::secret::{AccessToken, LinkToken};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Default)]
 pub enum Language {
@@ -43,7 +44,7 @@ pub enum Language {
     Swedish,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default)]
 pub enum CountryCode {
     #[serde(rename = "BE")]
     Be,
@@ -82,6 +83,35 @@ pub enum CountryCode {
     #[default]
     #[serde(rename = "US")]
     Us,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl CountryCode {
+    /// ISO 3166-1 alpha-2 code, as sent on the wire
+    pub fn alpha2(&self) -> &str {
+        match self {
+            CountryCode::Be => "BE",
+            CountryCode::Ca => "CA",
+            CountryCode::De => "DE",
+            CountryCode::Dk => "DK",
+            CountryCode::Ee => "EE",
+            CountryCode::Es => "ES",
+            CountryCode::Fr => "FR",
+            CountryCode::Ie => "IE",
+            CountryCode::It => "IT",
+            CountryCode::Lt => "LT",
+            CountryCode::Lv => "LV",
+            CountryCode::Nl => "NL",
+            CountryCode::No => "NO",
+            CountryCode::Pl => "PL",
+            CountryCode::Pt => "PT",
+            CountryCode::Se => "SE",
+            CountryCode::Gb => "GB",
+            CountryCode::Us => "US",
+            CountryCode::Unknown(code) => code,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
@@ -97,7 +127,7 @@ pub struct E164PhoneNumber(String);
 impl E164PhoneNumber {
     pub fn new(phone_number: &str) -> PlaidResult<E164PhoneNumber> {
         let re = Regex::new(r"^\+[1-9]\d{1,14}$").expect("incorrect E164 phone number regex");
-        if re.is_match(&phone_number) {
+        if re.is_match(phone_number) {
             Ok(E164PhoneNumber(phone_number.to_string()))
         } else {
             Err(PlaidError::InvalidPhoneNumber(phone_number.to_string()))
@@ -105,6 +135,16 @@ impl E164PhoneNumber {
     }
 }
 
+impl<'de> Deserialize<'de> for E164PhoneNumber {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        E164PhoneNumber::new(&raw).map_err(serde::de::Error::custom)
+    }
+}
+
 impl fmt::Display for E164PhoneNumber {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -120,21 +160,13 @@ impl Serialize for E164PhoneNumber {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
-pub struct User {
-    pub client_user_id: String,
-    pub legal_name: Option<String>,
-    pub name: Option<UserName>,
-    pub phone_number: Option<E164PhoneNumber>,
-}
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Dob(String);
 
 impl Dob {
     pub fn new(date_of_birth: &str) -> PlaidResult<Dob> {
         let re = Regex::new(r"^\d{4}-\d{2}-\d{2}$").expect("Invalid date of birth regex");
-        if re.is_match(&date_of_birth) {
+        if re.is_match(date_of_birth) {
             Ok(Dob(date_of_birth.to_string()))
         } else {
             Err(PlaidError::InvalidDateOfBirth(date_of_birth.to_string()))
@@ -157,14 +189,197 @@ impl Serialize for Dob {
     }
 }
 
+const US_REGIONS: &[&str] = &[
+    "AK", "AL", "AR", "AS", "AZ", "CA", "CO", "CT", "DC", "DE", "FL", "GA", "GU", "HI", "IA", "ID",
+    "IL", "IN", "KS", "KY", "LA", "MA", "MD", "ME", "MI", "MN", "MO", "MP", "MS", "MT", "NC", "ND",
+    "NE", "NH", "NJ", "NM", "NV", "NY", "OH", "OK", "OR", "PA", "PR", "RI", "SC", "SD", "TN", "TX",
+    "UM", "UT", "VA", "VI", "VT", "WA", "WI", "WV", "WY",
+];
+
+const CA_REGIONS: &[&str] = &[
+    "AB", "BC", "MB", "NB", "NL", "NS", "NT", "NU", "ON", "PE", "QC", "SK", "YT",
+];
+
+/// The subdivision part of an ISO 3166-2 code, e.g. `NY` for `US-NY`.
+/// [`Address::region`] builds it against the address's own country.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region(String);
+
+impl Region {
+    /// Accepts either the bare subdivision (`"NY"`) or the full ISO 3166-2
+    /// code (`"US-NY"`). US states and territories and Canadian provinces are
+    /// checked against the full list; other countries only by format.
+    pub fn new(region: &str, country: CountryCode) -> PlaidResult<Region> {
+        let upper = region.trim().to_uppercase();
+        let prefix = format!("{}-", country.alpha2());
+        let subdivision = upper.strip_prefix(&prefix).unwrap_or(&upper);
+
+        let valid = match &country {
+            CountryCode::Us => US_REGIONS.contains(&subdivision),
+            CountryCode::Ca => CA_REGIONS.contains(&subdivision),
+            _ => Regex::new(r"^[A-Z0-9]{1,3}$")
+                .expect("incorrect ISO 3166-2 subdivision regex")
+                .is_match(subdivision),
+        };
+
+        if valid {
+            Ok(Region(subdivision.to_string()))
+        } else {
+            Err(PlaidError::InvalidRegion {
+                region: region.to_string(),
+                country,
+            })
+        }
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serialize for Region {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+/// A postal code in the format used by its country, e.g. ZIP or ZIP+4 in the
+/// US and `A1A 1A1` in Canada. [`Address::postal_code`] builds it against
+/// the address's own country.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostalCode(String);
+
+impl PostalCode {
+    /// Letters are upper-cased and, for Canada and the UK, the inward and
+    /// outward parts are separated by a single space
+    pub fn new(postal_code: &str, country: CountryCode) -> PlaidResult<PostalCode> {
+        let compact: String = postal_code
+            .split_whitespace()
+            .collect::<String>()
+            .to_uppercase();
+
+        let (pattern, normalized) = match &country {
+            CountryCode::Us => (r"^\d{5}(-\d{4})?$", compact),
+            CountryCode::Ca => (
+                r"^[ABCEGHJ-NPRSTVXY]\d[ABCEGHJ-NPRSTV-Z] \d[ABCEGHJ-NPRSTV-Z]\d$",
+                split_inward(&compact),
+            ),
+            CountryCode::Gb => (r"^[A-Z]{1,2}\d[A-Z\d]? \d[A-Z]{2}$", split_inward(&compact)),
+            CountryCode::Ie => (r"^([AC-FHKNPRTV-Y]\d{2}|D6W)[AC-FHKNPRTV-Y\d]{4}$", compact),
+            CountryCode::Nl => (r"^\d{4}[A-Z]{2}$", compact),
+            CountryCode::Pl => (r"^\d{2}-\d{3}$", compact),
+            CountryCode::Pt => (r"^\d{4}-\d{3}$", compact),
+            CountryCode::Se => (r"^\d{5}$", compact),
+            CountryCode::Lt => (r"^(LT-)?\d{5}$", compact),
+            CountryCode::Lv => (r"^(LV-)?\d{4}$", compact),
+            CountryCode::Be | CountryCode::Dk | CountryCode::No => (r"^\d{4}$", compact),
+            CountryCode::De
+            | CountryCode::Ee
+            | CountryCode::Es
+            | CountryCode::Fr
+            | CountryCode::It => (r"^\d{5}$", compact),
+            CountryCode::Unknown(_) => (r"^[A-Z0-9][A-Z0-9-]{1,9}$", compact),
+        };
+
+        let re = Regex::new(pattern).expect("incorrect postal code regex");
+        if re.is_match(&normalized) {
+            Ok(PostalCode(normalized))
+        } else {
+            Err(PlaidError::InvalidPostalCode {
+                postal_code: postal_code.to_string(),
+                country,
+            })
+        }
+    }
+}
+
+/// Inserts the space before the three-character inward code
+fn split_inward(compact: &str) -> String {
+    match compact.len().checked_sub(3) {
+        Some(at) if at > 0 && compact.is_ascii() => {
+            format!("{} {}", &compact[..at], &compact[at..])
+        }
+        _ => compact.to_string(),
+    }
+}
+
+impl fmt::Display for PostalCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serialize for PostalCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Address {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub city: Option<String>,
-    // TODO:  ISO 3166-2 subdivision code
-    pub region: Option<String>,
-    // TODO
-    pub postal_code: Option<String>,
-    pub country: CountryCode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<Region>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    postal_code: Option<PostalCode>,
+    country: CountryCode,
+}
+
+/// The country is fixed on construction so that the region and postal code
+/// are always checked against the country they are sent with
+impl Address {
+    pub fn new(country: CountryCode) -> Self {
+        Self {
+            street: None,
+            street2: None,
+            city: None,
+            region: None,
+            postal_code: None,
+            country,
+        }
+    }
+
+    pub fn street(mut self, street: &str) -> Self {
+        self.street = Some(street.into());
+        self
+    }
+
+    pub fn street2(mut self, street2: &str) -> Self {
+        self.street2 = Some(street2.into());
+        self
+    }
+
+    pub fn city(mut self, city: &str) -> Self {
+        self.city = Some(city.into());
+        self
+    }
+
+    pub fn region(mut self, region: &str) -> PlaidResult<Self> {
+        self.region = Some(Region::new(region, self.country.clone())?);
+        Ok(self)
+    }
+
+    pub fn postal_code(mut self, postal_code: &str) -> PlaidResult<Self> {
+        self.postal_code = Some(PostalCode::new(postal_code, self.country.clone())?);
+        Ok(self)
+    }
+
+    pub fn country(&self) -> &CountryCode {
+        &self.country
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
@@ -249,40 +464,688 @@ pub struct IdNumber {
     pub type_: IdType,
 }
 
+/// Identity details Plaid uses to pre-fill Link and to verify the user. Unset
+/// fields are left out of the request rather than sent as `null`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+pub struct User {
+    pub client_user_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<UserName>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<E164PhoneNumber>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_of_birth: Option<Dob>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_number: Option<IdNumber>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+pub enum DepositoryAccountSubtype {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "cash management")]
+    CashManagement,
+    #[serde(rename = "cd")]
+    Cd,
+    #[serde(rename = "checking")]
+    Checking,
+    #[serde(rename = "ebt")]
+    Ebt,
+    #[serde(rename = "hsa")]
+    Hsa,
+    #[serde(rename = "money market")]
+    MoneyMarket,
+    #[serde(rename = "paypal")]
+    Paypal,
+    #[serde(rename = "prepaid")]
+    Prepaid,
+    #[serde(rename = "savings")]
+    Savings,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+pub enum CreditAccountSubtype {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "credit card")]
+    CreditCard,
+    #[serde(rename = "paypal")]
+    Paypal,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+pub enum LoanAccountSubtype {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "auto")]
+    Auto,
+    #[serde(rename = "business")]
+    Business,
+    #[serde(rename = "commercial")]
+    Commercial,
+    #[serde(rename = "construction")]
+    Construction,
+    #[serde(rename = "consumer")]
+    Consumer,
+    #[serde(rename = "home equity")]
+    HomeEquity,
+    #[serde(rename = "line of credit")]
+    LineOfCredit,
+    #[serde(rename = "loan")]
+    Loan,
+    #[serde(rename = "mortgage")]
+    Mortgage,
+    #[serde(rename = "other")]
+    Other,
+    #[serde(rename = "overdraft")]
+    Overdraft,
+    #[serde(rename = "student")]
+    Student,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+pub enum InvestmentAccountSubtype {
+    #[serde(rename = "529")]
+    Education529,
+    #[serde(rename = "401a")]
+    Retirement401a,
+    #[serde(rename = "401k")]
+    Retirement401k,
+    #[serde(rename = "403B")]
+    Retirement403b,
+    #[serde(rename = "457b")]
+    Retirement457b,
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "brokerage")]
+    Brokerage,
+    #[serde(rename = "cash isa")]
+    CashIsa,
+    #[serde(rename = "crypto exchange")]
+    CryptoExchange,
+    #[serde(rename = "education savings account")]
+    EducationSavingsAccount,
+    #[serde(rename = "fixed annuity")]
+    FixedAnnuity,
+    #[serde(rename = "gic")]
+    Gic,
+    #[serde(rename = "health reimbursement arrangement")]
+    HealthReimbursementArrangement,
+    #[serde(rename = "hsa")]
+    Hsa,
+    #[serde(rename = "ira")]
+    Ira,
+    #[serde(rename = "isa")]
+    Isa,
+    #[serde(rename = "keogh")]
+    Keogh,
+    #[serde(rename = "lif")]
+    Lif,
+    #[serde(rename = "life insurance")]
+    LifeInsurance,
+    #[serde(rename = "lira")]
+    Lira,
+    #[serde(rename = "lrif")]
+    Lrif,
+    #[serde(rename = "lrsp")]
+    Lrsp,
+    #[serde(rename = "mutual fund")]
+    MutualFund,
+    #[serde(rename = "non-taxable brokerage account")]
+    NonTaxableBrokerageAccount,
+    #[serde(rename = "other")]
+    Other,
+    #[serde(rename = "other annuity")]
+    OtherAnnuity,
+    #[serde(rename = "other insurance")]
+    OtherInsurance,
+    #[serde(rename = "pension")]
+    Pension,
+    #[serde(rename = "prif")]
+    Prif,
+    #[serde(rename = "profit sharing plan")]
+    ProfitSharingPlan,
+    #[serde(rename = "qshr")]
+    Qshr,
+    #[serde(rename = "rdsp")]
+    Rdsp,
+    #[serde(rename = "resp")]
+    Resp,
+    #[serde(rename = "retirement")]
+    Retirement,
+    #[serde(rename = "rlif")]
+    Rlif,
+    #[serde(rename = "roth")]
+    Roth,
+    #[serde(rename = "roth 401k")]
+    Roth401k,
+    #[serde(rename = "rrif")]
+    Rrif,
+    #[serde(rename = "rrsp")]
+    Rrsp,
+    #[serde(rename = "sarsep")]
+    Sarsep,
+    #[serde(rename = "sep ira")]
+    SepIra,
+    #[serde(rename = "simple ira")]
+    SimpleIra,
+    #[serde(rename = "sipp")]
+    Sipp,
+    #[serde(rename = "stock plan")]
+    StockPlan,
+    #[serde(rename = "tfsa")]
+    Tfsa,
+    #[serde(rename = "trust")]
+    Trust,
+    #[serde(rename = "ugma")]
+    Ugma,
+    #[serde(rename = "utma")]
+    Utma,
+    #[serde(rename = "variable annuity")]
+    VariableAnnuity,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-pub struct AccountFilters {}
+pub struct DepositoryFilter {
+    pub account_subtypes: Vec<DepositoryAccountSubtype>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CreditFilter {
+    pub account_subtypes: Vec<CreditAccountSubtype>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LoanFilter {
+    pub account_subtypes: Vec<LoanAccountSubtype>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InvestmentFilter {
+    pub account_subtypes: Vec<InvestmentAccountSubtype>,
+}
+
+/// Restricts which accounts are shown in Link. Account types left as `None`
+/// are hidden entirely once any filter is set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+pub struct AccountFilters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depository: Option<DepositoryFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit: Option<CreditFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loan: Option<LoanFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub investment: Option<InvestmentFilter>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RerouteToCredentials {
+    Off,
+    Optional,
+    Forced,
+}
+
+/// Configures the Auth product's verification flows
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+pub struct LinkTokenCreateRequestAuth {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_type_select_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automated_microdeposits_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instant_match_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub same_day_microdeposits_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instant_microdeposits_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_microdeposits_verification_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database_match_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database_insights_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reroute_to_credentials: Option<RerouteToCredentials>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+pub struct LinkTokenCreateRequestTransactions {
+    /// Between 1 and 730, defaults to 90
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_requested: Option<u32>,
+}
+
+/// Options for update mode, i.e. when `access_token` is set
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+pub struct LinkTokenCreateRequestUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_selection_enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+pub struct LinkTokenEuConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headless: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+pub struct LinkTokenCreateInstitutionData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_number: Option<String>,
+}
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
 pub struct LinkTokenCreateRequest {
-    pub client_id: String,
-    pub secret: String,
     pub client_name: String,
     pub language: Language,
     pub country_codes: Vec<CountryCode>,
     pub user: User,
-    // TODO - these cause issues if present (are not required)
-    // pub email_address: Option<String>,
-    // pub date_of_birth: Option<Dob>,
-    // // pub address: Option<Address>,
-    // pub id_number: Option<IdNumber>,
     pub products: Vec<Product>,
-    pub required_if_supported_products: Option<Vec<Product>>, // TODO
-    pub optional_products: Option<Vec<Product>>,              // TODO
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_if_supported_products: Option<Vec<Product>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional_products: Option<Vec<Product>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_consented_products: Option<Vec<Product>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub webhook: Option<String>,
-    pub access_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_token: Option<AccessToken>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub link_customization_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub redirect_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub android_package_name: Option<String>,
-    pub institution_data: Option<HashMap<String, String>>, // TODO
-    pub account_filters: Option<AccountFilters>,           // TODO
-                                                           // TODO - remaining fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub institution_data: Option<LinkTokenCreateInstitutionData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_filters: Option<AccountFilters>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eu_config: Option<LinkTokenEuConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth: Option<LinkTokenCreateRequestAuth>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transactions: Option<LinkTokenCreateRequestTransactions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update: Option<LinkTokenCreateRequestUpdate>,
+}
+
+impl LinkTokenCreateRequest {
+    /// Starts a request with the fields Plaid always requires
+    pub fn builder(
+        client_name: &str,
+        user: User,
+        country_codes: Vec<CountryCode>,
+        products: Vec<Product>,
+    ) -> LinkTokenCreateRequestBuilder {
+        LinkTokenCreateRequestBuilder::new(client_name, user, country_codes, products)
+    }
+}
+
+pub struct LinkTokenCreateRequestBuilder {
+    request: LinkTokenCreateRequest,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+impl LinkTokenCreateRequestBuilder {
+    pub fn new(
+        client_name: &str,
+        user: User,
+        country_codes: Vec<CountryCode>,
+        products: Vec<Product>,
+    ) -> Self {
+        Self {
+            request: LinkTokenCreateRequest {
+                client_name: client_name.into(),
+                country_codes,
+                user,
+                products,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn language(mut self, language: Language) -> Self {
+        self.request.language = language;
+        self
+    }
+
+    pub fn required_if_supported_products(mut self, products: Vec<Product>) -> Self {
+        self.request.required_if_supported_products = Some(products);
+        self
+    }
+
+    pub fn optional_products(mut self, products: Vec<Product>) -> Self {
+        self.request.optional_products = Some(products);
+        self
+    }
+
+    pub fn additional_consented_products(mut self, products: Vec<Product>) -> Self {
+        self.request.additional_consented_products = Some(products);
+        self
+    }
+
+    pub fn webhook(mut self, webhook: &str) -> Self {
+        self.request.webhook = Some(webhook.into());
+        self
+    }
+
+    /// Puts Link in update mode for an existing item
+    pub fn access_token(mut self, access_token: AccessToken) -> Self {
+        self.request.access_token = Some(access_token);
+        self
+    }
+
+    pub fn link_customization_name(mut self, link_customization_name: &str) -> Self {
+        self.request.link_customization_name = Some(link_customization_name.into());
+        self
+    }
+
+    pub fn redirect_uri(mut self, redirect_uri: &str) -> Self {
+        self.request.redirect_uri = Some(redirect_uri.into());
+        self
+    }
+
+    pub fn android_package_name(mut self, android_package_name: &str) -> Self {
+        self.request.android_package_name = Some(android_package_name.into());
+        self
+    }
+
+    pub fn institution_data(mut self, institution_data: LinkTokenCreateInstitutionData) -> Self {
+        self.request.institution_data = Some(institution_data);
+        self
+    }
+
+    pub fn account_filters(mut self, account_filters: AccountFilters) -> Self {
+        self.request.account_filters = Some(account_filters);
+        self
+    }
+
+    pub fn eu_config(mut self, eu_config: LinkTokenEuConfig) -> Self {
+        self.request.eu_config = Some(eu_config);
+        self
+    }
+
+    pub fn auth(mut self, auth: LinkTokenCreateRequestAuth) -> Self {
+        self.request.auth = Some(auth);
+        self
+    }
+
+    pub fn transactions(mut self, transactions: LinkTokenCreateRequestTransactions) -> Self {
+        self.request.transactions = Some(transactions);
+        self
+    }
+
+    pub fn update(mut self, update: LinkTokenCreateRequestUpdate) -> Self {
+        self.request.update = Some(update);
+        self
+    }
+
+    /// Checks the cross-field rules Plaid would otherwise reject the request for
+    pub fn build(self) -> PlaidResult<LinkTokenCreateRequest> {
+        let request = self.request;
+
+        if request.client_name.trim().is_empty() {
+            return Err(PlaidError::EmptyClientName);
+        }
+
+        if request.country_codes.is_empty() {
+            return Err(PlaidError::NoCountryCodes);
+        }
+
+        if request.products.is_empty() {
+            return Err(PlaidError::NoProducts);
+        }
+
+        if request.redirect_uri.is_some() && request.android_package_name.is_some() {
+            return Err(PlaidError::ConflictingRedirect);
+        }
+
+        let mut seen: Vec<&Product> = Vec::new();
+        let listed = request
+            .products
+            .iter()
+            .chain(request.required_if_supported_products.iter().flatten())
+            .chain(request.optional_products.iter().flatten());
+        for product in listed {
+            if seen.contains(&product) {
+                return Err(PlaidError::DuplicateProduct(product.clone()));
+            }
+            seen.push(product);
+        }
+
+        Ok(request)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LinkTokenCreateResponse {
     pub expiration: DateTime<Utc>,
-    pub link_token: String,
+    pub link_token: LinkToken,
     pub request_id: String,
     pub hosted_link_url: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builder(
+        client_name: &str,
+        country_codes: Vec<CountryCode>,
+        products: Vec<Product>,
+    ) -> LinkTokenCreateRequestBuilder {
+        let user = User {
+            client_user_id: "user-1".into(),
+            ..Default::default()
+        };
+        LinkTokenCreateRequest::builder(client_name, user, country_codes, products)
+    }
+
+    #[test]
+    fn builder_accepts_a_minimal_request() {
+        let request = builder(
+            "Tiny Quickstart",
+            vec![CountryCode::Us],
+            vec![Product::Auth],
+        )
+        .build()
+        .unwrap();
+        assert_eq!(request.client_name, "Tiny Quickstart");
+        assert_eq!(request.products, vec![Product::Auth]);
+    }
+
+    #[test]
+    fn builder_rejects_what_plaid_rejects() {
+        let us = || vec![CountryCode::Us];
+        let auth = || vec![Product::Auth];
+
+        assert!(matches!(
+            builder(" ", us(), auth()).build(),
+            Err(PlaidError::EmptyClientName)
+        ));
+        assert!(matches!(
+            builder("app", vec![], auth()).build(),
+            Err(PlaidError::NoCountryCodes)
+        ));
+        assert!(matches!(
+            builder("app", us(), vec![]).build(),
+            Err(PlaidError::NoProducts)
+        ));
+        assert!(matches!(
+            builder("app", us(), auth())
+                .redirect_uri("https://example.com/oauth")
+                .android_package_name("com.example.app")
+                .build(),
+            Err(PlaidError::ConflictingRedirect)
+        ));
+        assert!(matches!(
+            builder("app", us(), auth())
+                .optional_products(vec![Product::Identity, Product::Auth])
+                .build(),
+            Err(PlaidError::DuplicateProduct(Product::Auth))
+        ));
+    }
+
+    #[test]
+    fn unset_fields_are_left_out_of_the_request() {
+        let request = builder(
+            "Tiny Quickstart",
+            vec![CountryCode::Us],
+            vec![Product::Auth],
+        )
+        .auth(LinkTokenCreateRequestAuth::default())
+        .update(LinkTokenCreateRequestUpdate::default())
+        .eu_config(LinkTokenEuConfig::default())
+        .institution_data(LinkTokenCreateInstitutionData::default())
+        .build()
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            serde_json::json!({
+                "client_name": "Tiny Quickstart",
+                "language": "en",
+                "country_codes": ["US"],
+                "user": { "client_user_id": "user-1" },
+                "products": ["auth"],
+                "auth": {},
+                "update": {},
+                "eu_config": {},
+                "institution_data": {},
+            })
+        );
+    }
+
+    #[test]
+    fn sub_objects_serialize_to_plaid_shapes() {
+        let user = User {
+            client_user_id: "user-1".into(),
+            legal_name: Some("Alberta Charleson".into()),
+            phone_number: Some(E164PhoneNumber::new("+14155550011").unwrap()),
+            ..Default::default()
+        };
+        let request = LinkTokenCreateRequest::builder(
+            "Tiny Quickstart",
+            user,
+            vec![CountryCode::Us],
+            vec![Product::Auth],
+        )
+        .account_filters(AccountFilters {
+            depository: Some(DepositoryFilter {
+                account_subtypes: vec![
+                    DepositoryAccountSubtype::Checking,
+                    DepositoryAccountSubtype::Savings,
+                ],
+            }),
+            ..Default::default()
+        })
+        .auth(LinkTokenCreateRequestAuth {
+            same_day_microdeposits_enabled: Some(true),
+            reroute_to_credentials: Some(RerouteToCredentials::Optional),
+            ..Default::default()
+        })
+        .update(LinkTokenCreateRequestUpdate {
+            account_selection_enabled: Some(true),
+        })
+        .eu_config(LinkTokenEuConfig {
+            headless: Some(false),
+        })
+        .institution_data(LinkTokenCreateInstitutionData {
+            routing_number: Some("011401533".into()),
+        })
+        .build()
+        .unwrap();
+
+        let json = serde_json::to_value(request).unwrap();
+        assert_eq!(
+            json["user"],
+            serde_json::json!({
+                "client_user_id": "user-1",
+                "legal_name": "Alberta Charleson",
+                "phone_number": "+14155550011",
+            })
+        );
+        assert_eq!(
+            json["account_filters"],
+            serde_json::json!({ "depository": { "account_subtypes": ["checking", "savings"] } })
+        );
+        assert_eq!(
+            json["auth"],
+            serde_json::json!({
+                "same_day_microdeposits_enabled": true,
+                "reroute_to_credentials": "optional",
+            })
+        );
+        assert_eq!(
+            json["update"],
+            serde_json::json!({ "account_selection_enabled": true })
+        );
+        assert_eq!(json["eu_config"], serde_json::json!({ "headless": false }));
+        assert_eq!(
+            json["institution_data"],
+            serde_json::json!({ "routing_number": "011401533" })
+        );
+    }
+
+    #[test]
+    fn address_normalizes_region_and_postal_code() {
+        let address = Address::new(CountryCode::Ca)
+            .city("Toronto")
+            .region("ca-on")
+            .unwrap()
+            .postal_code("m5v3l9")
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(address).unwrap(),
+            serde_json::json!({
+                "city": "Toronto",
+                "region": "ON",
+                "postal_code": "M5V 3L9",
+                "country": "CA",
+            })
+        );
+    }
+
+    #[test]
+    fn region_and_postal_code_validate_on_their_own() {
+        assert_eq!(
+            Region::new("us-ny", CountryCode::Us).unwrap().to_string(),
+            "NY"
+        );
+        assert!(Region::new("ON", CountryCode::Us).is_err());
+        assert_eq!(
+            PostalCode::new("sw1a 1aa", CountryCode::Gb)
+                .unwrap()
+                .to_string(),
+            "SW1A 1AA"
+        );
+        assert!(PostalCode::new("1234", CountryCode::Us).is_err());
+    }
+
+    #[test]
+    fn address_checks_against_its_own_country() {
+        assert!(Address::new(CountryCode::Us).region("NY").is_ok());
+        assert!(matches!(
+            Address::new(CountryCode::Us).region("ZZ"),
+            Err(PlaidError::InvalidRegion { .. })
+        ));
+        assert!(matches!(
+            Address::new(CountryCode::Gb).postal_code("10001"),
+            Err(PlaidError::InvalidPostalCode { .. })
+        ));
+        assert!(Address::new(CountryCode::Gb).postal_code("SW1A1AA").is_ok());
+        assert!(matches!(
+            Address::new(CountryCode::Us).postal_code("SW1A 1AA"),
+            Err(PlaidError::InvalidPostalCode { .. })
+        ));
+    }
+}
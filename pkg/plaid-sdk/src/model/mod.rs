@@ -1,6 +1,12 @@
 pub mod accounts;
+pub mod auth;
 pub mod common;
 pub mod error;
+pub mod identity;
+pub mod institutions;
 pub mod items;
 pub mod link;
+pub mod sandbox;
+pub mod secret;
+pub mod transactions;
 pub mod transfer;
@@ -0,0 +1,105 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::secret::{AccessToken, PublicToken};
+use super::{
+    common::Product,
+    transfer::{TransferEventType, TransferFailure},
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SandboxPublicTokenCreateTransactionsOptions {
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SandboxPublicTokenCreateRequestOptions {
+    pub webhook: Option<String>,
+    /// Defaults to `user_good`
+    pub override_username: Option<String>,
+    /// Defaults to `pass_good`
+    pub override_password: Option<String>,
+    pub transactions: Option<SandboxPublicTokenCreateTransactionsOptions>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SandboxPublicTokenCreateRequest {
+    /// e.g. `ins_109508` (First Platypus Bank)
+    pub institution_id: String,
+    pub initial_products: Vec<Product>,
+    pub options: Option<SandboxPublicTokenCreateRequestOptions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SandboxPublicTokenCreateResponse {
+    pub public_token: PublicToken,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SandboxItemResetLoginRequest {
+    pub access_token: AccessToken,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SandboxItemResetLoginResponse {
+    pub reset_login: bool,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WebhookType {
+    Auth,
+    Holdings,
+    InvestmentsTransactions,
+    Item,
+    Liabilities,
+    Transactions,
+    Assets,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SandboxWebhookCode {
+    #[default]
+    DefaultUpdate,
+    NewAccountsAvailable,
+    SmsMicrodepositsVerification,
+    AuthDataUpdate,
+    LoginRepaired,
+    RecurringTransactionsUpdate,
+    SyncUpdatesAvailable,
+    ProductReady,
+    Error,
+    UserPermissionRevoked,
+    UserAccountRevoked,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SandboxItemFireWebhookRequest {
+    pub access_token: AccessToken,
+    pub webhook_type: Option<WebhookType>,
+    pub webhook_code: SandboxWebhookCode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SandboxItemFireWebhookResponse {
+    pub webhook_fired: bool,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SandboxTransferSimulateRequest {
+    pub transfer_id: String,
+    /// One of `posted`, `settled`, `failed`, `funds_available` or `returned`
+    pub event_type: TransferEventType,
+    pub failure_reason: Option<TransferFailure>,
+    pub test_clock_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SandboxTransferSimulateResponse {
+    pub request_id: String,
+}
@@ -0,0 +1,137 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+/// Defines a string newtype that never prints its contents and wipes its
+/// memory when dropped. The raw value is only reachable via `expose_secret`.
+macro_rules! secret_string {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Default, PartialEq, Eq)]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn new(value: impl Into<String>) -> Self {
+                Self(value.into())
+            }
+
+            pub fn expose_secret(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                Self(value.into())
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}([REDACTED])", stringify!($name))
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "[REDACTED]")
+            }
+        }
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                self.0.zeroize();
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                String::deserialize(deserializer).map(Self)
+            }
+        }
+    };
+}
+
+/// Tokens are part of request bodies, so they serialize to their raw value
+macro_rules! serialize_secret {
+    ($name:ident) => {
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(&self.0)
+            }
+        }
+    };
+}
+
+secret_string!(
+    /// Long-lived credential for an item, returned by `/item/public_token/exchange`
+    AccessToken
+);
+serialize_secret!(AccessToken);
+
+secret_string!(
+    /// Short-lived token handed out by Link on success
+    PublicToken
+);
+serialize_secret!(PublicToken);
+
+secret_string!(
+    /// Token used to initialise Link on the client
+    LinkToken
+);
+serialize_secret!(LinkToken);
+
+secret_string!(
+    /// The Plaid API secret; only ever sent in the `PLAID-SECRET` header
+    ClientSecret
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::accounts::AccountsBalanceGetRequest;
+
+    #[test]
+    fn secrets_never_print_their_value() {
+        let token = AccessToken::new("access-sandbox-1234");
+        assert_eq!(format!("{token:?}"), "AccessToken([REDACTED])");
+        assert_eq!(format!("{token}"), "[REDACTED]");
+
+        let secret = ClientSecret::new("plaid-secret");
+        assert_eq!(format!("{secret:?}"), "ClientSecret([REDACTED])");
+        assert_eq!(format!("{secret}"), "[REDACTED]");
+    }
+
+    #[test]
+    fn requests_holding_a_token_debug_without_it() {
+        let req = AccountsBalanceGetRequest {
+            access_token: AccessToken::new("access-sandbox-1234"),
+            ..Default::default()
+        };
+        let debug = format!("{req:?} {req:#?}");
+        assert!(!debug.contains("access-sandbox-1234"), "{debug}");
+        assert!(debug.contains("[REDACTED]"));
+    }
+
+    #[test]
+    fn tokens_serialize_to_their_raw_value() {
+        let token = PublicToken::new("public-sandbox-1234");
+        assert_eq!(
+            serde_json::to_string(&token).unwrap(),
+            "\"public-sandbox-1234\""
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::accounts::Account;
+use super::common::{Amount, Currency, Money};
+use super::secret::AccessToken;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionsSyncRequestOptions {
+    pub include_original_description: Option<bool>,
+    pub include_personal_finance_category: Option<bool>,
+    /// Between 1 and 730, defaults to 90
+    pub days_requested: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct TransactionsSyncRequest {
+    pub access_token: AccessToken,
+    /// `None` (or an empty string) fetches the full history of the item
+    pub cursor: Option<String>,
+    /// Between 1 and 500, defaults to 100
+    pub count: Option<u32>,
+    pub options: Option<TransactionsSyncRequestOptions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransactionsUpdateStatus {
+    TransactionsUpdateStatusUnknown,
+    NotReady,
+    InitialUpdateComplete,
+    HistoricalUpdateComplete,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaymentChannel {
+    #[serde(rename = "online")]
+    Online,
+    #[serde(rename = "in store")]
+    InStore,
+    #[serde(rename = "other")]
+    Other,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionCode {
+    Adjustment,
+    Atm,
+    BankCharge,
+    BillPayment,
+    Cash,
+    Cashback,
+    Cheque,
+    DirectDebit,
+    Interest,
+    Purchase,
+    StandingOrder,
+    Transfer,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PersonalFinanceCategoryConfidenceLevel {
+    VeryHigh,
+    High,
+    Medium,
+    Low,
+    Unknown,
+    /// Plaid already uses `unknown` for this field, so unseen values land here
+    #[serde(untagged)]
+    Unrecognized(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonalFinanceCategory {
+    /// e.g. `FOOD_AND_DRINK`
+    pub primary: String,
+    /// e.g. `FOOD_AND_DRINK_COFFEE`
+    pub detailed: String,
+    pub confidence_level: Option<PersonalFinanceCategoryConfidenceLevel>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TransactionLocation {
+    pub address: Option<String>,
+    pub city: Option<String>,
+    pub region: Option<String>,
+    pub postal_code: Option<String>,
+    pub country: Option<String>,
+    pub lat: Option<f64>,
+    pub lon: Option<f64>,
+    pub store_number: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Transaction {
+    pub transaction_id: String,
+    pub account_id: String,
+    /// Positive values are outflows (debits), negative values are inflows (credits)
+    pub amount: Amount,
+    #[serde(flatten, with = "super::common::currency_codes")]
+    pub currency: Option<Currency>,
+    pub date: NaiveDate,
+    pub authorized_date: Option<NaiveDate>,
+    pub datetime: Option<DateTime<Utc>>,
+    pub authorized_datetime: Option<DateTime<Utc>>,
+    pub name: String,
+    pub merchant_name: Option<String>,
+    pub merchant_entity_id: Option<String>,
+    pub logo_url: Option<String>,
+    pub website: Option<String>,
+    pub original_description: Option<String>,
+    pub pending: bool,
+    pub pending_transaction_id: Option<String>,
+    pub account_owner: Option<String>,
+    pub payment_channel: PaymentChannel,
+    pub transaction_code: Option<TransactionCode>,
+    pub personal_finance_category: Option<PersonalFinanceCategory>,
+    pub personal_finance_category_icon_url: Option<String>,
+    pub location: Option<TransactionLocation>,
+    pub check_number: Option<String>,
+}
+
+impl Transaction {
+    pub fn money(&self) -> Money {
+        Money::new(self.amount, self.currency.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemovedTransaction {
+    pub transaction_id: String,
+    pub account_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionsSyncResponse {
+    pub transactions_update_status: Option<TransactionsUpdateStatus>,
+    pub accounts: Option<Vec<Account>>,
+    pub added: Vec<Transaction>,
+    pub modified: Vec<Transaction>,
+    pub removed: Vec<RemovedTransaction>,
+    pub next_cursor: String,
+    pub has_more: bool,
+    pub request_id: String,
+}
+
+/// Every page of `/transactions/sync` since a cursor, merged together
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TransactionsSyncUpdate {
+    pub added: Vec<Transaction>,
+    pub modified: Vec<Transaction>,
+    pub removed: Vec<RemovedTransaction>,
+    /// Persist this and pass it as `cursor` on the next sync
+    pub next_cursor: String,
+}
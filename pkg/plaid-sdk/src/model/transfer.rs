@@ -0,0 +1,510 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{common::Amount, secret::AccessToken};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TransferType {
+    #[default]
+    Debit,
+    Credit,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum TransferNetwork {
+    #[default]
+    #[serde(rename = "ach")]
+    Ach,
+    #[serde(rename = "same-day-ach")]
+    SameDayAch,
+    #[serde(rename = "rtp")]
+    Rtp,
+    #[serde(rename = "wire")]
+    Wire,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AchClass {
+    Ccd,
+    Ppd,
+    Tel,
+    Web,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct TransferUserAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct TransferUser {
+    pub legal_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<TransferUserAddress>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferDevice {
+    pub ip_address: String,
+    pub user_agent: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+pub struct TransferAuthorizationCreateRequest {
+    pub access_token: AccessToken,
+    pub account_id: String,
+    #[serde(rename = "type")]
+    pub type_: TransferType,
+    pub network: TransferNetwork,
+    #[serde(with = "super::common::decimal_string")]
+    pub amount: Amount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ach_class: Option<AchClass>,
+    pub user: TransferUser,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<TransferDevice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_present: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iso_currency_code: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferAuthorizationDecision {
+    Approved,
+    Declined,
+    UserActionRequired,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransferAuthorizationDecisionRationaleCode {
+    Nsf,
+    Risk,
+    TransferLimitReached,
+    ManuallyVerifiedItem,
+    ItemLoginRequired,
+    PaymentProfileLoginRequired,
+    Error,
+    MigratedAccountItem,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferAuthorizationDecisionRationale {
+    pub code: TransferAuthorizationDecisionRationaleCode,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferAuthorizationProposedTransfer {
+    pub ach_class: Option<AchClass>,
+    pub account_id: Option<String>,
+    pub funding_account_id: Option<String>,
+    #[serde(rename = "type")]
+    pub type_: TransferType,
+    pub user: TransferUser,
+    #[serde(with = "super::common::decimal_string")]
+    pub amount: Amount,
+    pub network: TransferNetwork,
+    pub iso_currency_code: String,
+    pub originator_client_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferAuthorization {
+    pub id: String,
+    pub created: DateTime<Utc>,
+    pub decision: TransferAuthorizationDecision,
+    pub decision_rationale: Option<TransferAuthorizationDecisionRationale>,
+    pub proposed_transfer: TransferAuthorizationProposedTransfer,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferAuthorizationCreateResponse {
+    pub authorization: TransferAuthorization,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+pub struct TransferCreateRequest {
+    pub access_token: AccessToken,
+    pub account_id: String,
+    pub authorization_id: String,
+    /// Defaults to the authorized amount
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        with = "super::common::decimal_string::option"
+    )]
+    pub amount: Option<Amount>,
+    /// At most 15 characters, shown on the user's bank statement
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iso_currency_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferStatus {
+    Pending,
+    Posted,
+    Settled,
+    FundsAvailable,
+    Cancelled,
+    Failed,
+    Returned,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferSweepStatus {
+    Unswept,
+    Swept,
+    SweptSettled,
+    ReturnSwept,
+    FundsAvailable,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferFailure {
+    pub ach_return_code: Option<String>,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Transfer {
+    pub id: String,
+    pub authorization_id: String,
+    pub ach_class: Option<AchClass>,
+    pub account_id: Option<String>,
+    pub funding_account_id: Option<String>,
+    #[serde(rename = "type")]
+    pub type_: TransferType,
+    pub user: TransferUser,
+    #[serde(with = "super::common::decimal_string")]
+    pub amount: Amount,
+    pub description: String,
+    pub created: DateTime<Utc>,
+    pub status: TransferStatus,
+    pub sweep_status: Option<TransferSweepStatus>,
+    pub network: TransferNetwork,
+    pub cancellable: bool,
+    pub failure_reason: Option<TransferFailure>,
+    pub metadata: Option<HashMap<String, String>>,
+    pub iso_currency_code: String,
+    pub standard_return_window: Option<NaiveDate>,
+    pub unauthorized_return_window: Option<NaiveDate>,
+    pub expected_settlement_date: Option<NaiveDate>,
+    pub originator_client_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferCreateResponse {
+    pub transfer: Transfer,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+pub struct TransferGetRequest {
+    pub transfer_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferGetResponse {
+    pub transfer: Transfer,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+pub struct TransferListRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<DateTime<Utc>>,
+    /// Between 1 and 25, defaults to 25
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub originator_client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub funding_account_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferListResponse {
+    pub transfers: Vec<Transfer>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+pub struct TransferCancelRequest {
+    pub transfer_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferCancelResponse {
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransferEventType {
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "cancelled")]
+    Cancelled,
+    #[serde(rename = "failed")]
+    Failed,
+    #[serde(rename = "posted")]
+    Posted,
+    #[serde(rename = "settled")]
+    Settled,
+    #[serde(rename = "funds_available")]
+    FundsAvailable,
+    #[serde(rename = "returned")]
+    Returned,
+    #[serde(rename = "swept")]
+    Swept,
+    #[serde(rename = "swept_settled")]
+    SweptSettled,
+    #[serde(rename = "return_swept")]
+    ReturnSwept,
+    #[serde(rename = "sweep.pending")]
+    SweepPending,
+    #[serde(rename = "sweep.posted")]
+    SweepPosted,
+    #[serde(rename = "sweep.settled")]
+    SweepSettled,
+    #[serde(rename = "sweep.returned")]
+    SweepReturned,
+    #[serde(rename = "sweep.failed")]
+    SweepFailed,
+    #[serde(rename = "sweep.funds_available")]
+    SweepFundsAvailable,
+    #[serde(rename = "refund.pending")]
+    RefundPending,
+    #[serde(rename = "refund.cancelled")]
+    RefundCancelled,
+    #[serde(rename = "refund.failed")]
+    RefundFailed,
+    #[serde(rename = "refund.posted")]
+    RefundPosted,
+    #[serde(rename = "refund.settled")]
+    RefundSettled,
+    #[serde(rename = "refund.returned")]
+    RefundReturned,
+    #[serde(rename = "refund.swept")]
+    RefundSwept,
+    #[serde(rename = "refund.return_swept")]
+    RefundReturnSwept,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferEvent {
+    /// Monotonically increasing; persist the last seen value as the next `after_id`
+    pub event_id: u64,
+    pub timestamp: DateTime<Utc>,
+    pub event_type: TransferEventType,
+    pub account_id: Option<String>,
+    pub funding_account_id: Option<String>,
+    pub transfer_id: String,
+    pub origination_account_id: Option<String>,
+    pub transfer_type: TransferType,
+    #[serde(with = "super::common::decimal_string")]
+    pub transfer_amount: Amount,
+    pub failure_reason: Option<TransferFailure>,
+    pub sweep_id: Option<String>,
+    #[serde(default, with = "super::common::decimal_string::option")]
+    pub sweep_amount: Option<Amount>,
+    pub refund_id: Option<String>,
+    pub originator_client_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+pub struct TransferEventListRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_type: Option<TransferType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_types: Option<Vec<TransferEventType>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sweep_id: Option<String>,
+    /// Between 1 and 25, defaults to 25
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub originator_client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub funding_account_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferEventListResponse {
+    pub transfer_events: Vec<TransferEvent>,
+    pub has_more: bool,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+pub struct TransferEventSyncRequest {
+    pub after_id: u64,
+    /// Between 1 and 500, defaults to 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferEventSyncResponse {
+    pub transfer_events: Vec<TransferEvent>,
+    pub has_more: bool,
+    pub request_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn unset_request_fields_are_left_out() {
+        let request = TransferAuthorizationCreateRequest {
+            access_token: AccessToken::new("access-sandbox-1234"),
+            account_id: "checking-0000".into(),
+            amount: "12.34".parse().unwrap(),
+            user: TransferUser {
+                legal_name: "Alberta Charleson".into(),
+                address: Some(TransferUserAddress {
+                    postal_code: Some("10001".into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            json!({
+                "access_token": "access-sandbox-1234",
+                "account_id": "checking-0000",
+                "type": "debit",
+                "network": "ach",
+                "amount": "12.34",
+                "user": {
+                    "legal_name": "Alberta Charleson",
+                    "address": { "postal_code": "10001" },
+                },
+            })
+        );
+
+        let request = TransferCreateRequest {
+            access_token: AccessToken::new("access-sandbox-1234"),
+            account_id: "checking-0000".into(),
+            authorization_id: "auth-1".into(),
+            description: "payment".into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            json!({
+                "access_token": "access-sandbox-1234",
+                "account_id": "checking-0000",
+                "authorization_id": "auth-1",
+                "description": "payment",
+            })
+        );
+
+        assert_eq!(
+            serde_json::to_value(TransferListRequest::default()).unwrap(),
+            json!({})
+        );
+    }
+
+    #[test]
+    fn transfer_decodes_from_plaid() {
+        let transfer: Transfer = serde_json::from_value(json!({
+            "id": "460cbe92-2dcc-8eae-5ad6-b37d0ec90fd9",
+            "authorization_id": "231h012308h3101z21909sw",
+            "ach_class": "ppd",
+            "account_id": "3gE5gnRzNmfaDYLJVLGrZeD7o48G3lcvjDPwe",
+            "funding_account_id": null,
+            "type": "credit",
+            "user": {
+                "legal_name": "Anne Charleston",
+                "phone_number": "510-555-0128",
+                "email_address": "acharleston@email.com",
+                "address": null,
+            },
+            "amount": "12.34",
+            "description": "payment",
+            "created": "2020-08-06T17:27:15Z",
+            "status": "posted",
+            "sweep_status": "swept",
+            "network": "same-day-ach",
+            "cancellable": false,
+            "failure_reason": null,
+            "metadata": { "key1": "value1" },
+            "iso_currency_code": "USD",
+            "standard_return_window": "2020-08-07",
+            "unauthorized_return_window": "2020-10-07",
+            "expected_settlement_date": "2020-08-04",
+            "originator_client_id": null,
+            "some_new_field": true,
+        }))
+        .unwrap();
+
+        assert_eq!(transfer.amount, "12.34".parse().unwrap());
+        assert_eq!(transfer.type_, TransferType::Credit);
+        assert_eq!(transfer.network, TransferNetwork::SameDayAch);
+        assert_eq!(transfer.status, TransferStatus::Posted);
+        assert_eq!(transfer.ach_class, Some(AchClass::Ppd));
+    }
+}
@@ -0,0 +1,196 @@
+use std::{fmt, time::Duration};
+
+use rand::Rng;
+
+use crate::error::PlaidError;
+
+/// Controls how [`crate::PlaidClient`] re-sends requests that fail transiently.
+///
+/// Delays grow exponentially from `base_delay`, capped at `max_delay`. With
+/// `jitter` enabled each delay is randomised between half and all of its
+/// value so that concurrent callers do not retry in lockstep.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub jitter: bool,
+    /// Decides whether a failed attempt should be retried
+    pub retry_on: fn(&PlaidError) -> bool,
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .field("jitter", &self.jitter)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(5),
+            jitter: true,
+            retry_on: RetryPolicy::is_transient,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Sends every request exactly once
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Defers to [`PlaidError::is_retryable`], so the catalog's remediation
+    /// decides which Plaid error codes are worth another attempt
+    pub fn is_transient(err: &PlaidError) -> bool {
+        err.is_retryable()
+    }
+
+    /// Requests that move money may only be replayed when Plaid can
+    /// deduplicate them, i.e. when they carry an idempotency key
+    pub fn for_money_movement(&self, idempotency_key: Option<&str>) -> Self {
+        match idempotency_key {
+            Some(_) => self.clone(),
+            None => Self::none(),
+        }
+    }
+
+    /// How long to wait after the given (1-based) failed attempt
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let exp = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)));
+        let delay = exp.min(self.max_delay);
+        if self.jitter {
+            let half = delay / 2;
+            half + rand::thread_rng().gen_range(Duration::ZERO..=half)
+        } else {
+            delay
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{
+            error::PlaidErrorResponse,
+            transfer::{TransferCreateRequest, TransferGetRequest},
+        },
+        traits::Endpoint,
+    };
+
+    fn api_error(error_type: &str, error_code: &str, status: u16) -> PlaidError {
+        let failure: PlaidErrorResponse = serde_json::from_value(serde_json::json!({
+            "display_message": null,
+            "documentation_url": "https://plaid.com/docs/errors/",
+            "error_code": error_code,
+            "error_message": "something went wrong",
+            "error_type": error_type,
+            "request_id": "abc123",
+            "status": status,
+        }))
+        .unwrap();
+        PlaidError::Api(Box::new(failure))
+    }
+
+    fn without_jitter() -> RetryPolicy {
+        RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+            jitter: false,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn delays_double_up_to_the_cap() {
+        let policy = without_jitter();
+        let delays: Vec<u64> = (1..=6)
+            .map(|attempt| policy.delay(attempt).as_millis() as u64)
+            .collect();
+
+        assert_eq!(delays, vec![100, 200, 400, 800, 1000, 1000]);
+        assert_eq!(policy.delay(u32::MAX), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn jitter_keeps_delays_between_half_and_all_of_the_backoff() {
+        let policy = RetryPolicy {
+            jitter: true,
+            ..without_jitter()
+        };
+
+        for attempt in 1..=6 {
+            let backoff = without_jitter().delay(attempt);
+            for _ in 0..50 {
+                let delay = policy.delay(attempt);
+                assert!(backoff / 2 <= delay && delay <= backoff, "{delay:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn default_policy_makes_three_attempts() {
+        assert_eq!(RetryPolicy::default().max_attempts, 3);
+        assert_eq!(RetryPolicy::none().max_attempts, 1);
+    }
+
+    #[test]
+    fn only_transient_failures_are_retried() {
+        let transient = [
+            api_error("RATE_LIMIT_EXCEEDED", "RATE_LIMIT", 429),
+            api_error("API_ERROR", "INTERNAL_SERVER_ERROR", 500),
+            api_error("API_ERROR", "SOME_NEW_CODE", 500),
+            PlaidError::UnexpectedStatus(503),
+        ];
+        for err in &transient {
+            assert!(err.is_retryable(), "{err:?}");
+            assert!(RetryPolicy::is_transient(err), "{err:?}");
+        }
+
+        let permanent = [
+            api_error("ITEM_ERROR", "ITEM_LOGIN_REQUIRED", 400),
+            api_error("INVALID_REQUEST", "INVALID_FIELD", 400),
+            api_error("INVALID_REQUEST", "SOME_NEW_CODE", 400),
+            PlaidError::UnexpectedStatus(404),
+            PlaidError::EmptyClientName,
+        ];
+        for err in &permanent {
+            assert!(!err.is_retryable(), "{err:?}");
+            assert!(!RetryPolicy::is_transient(err), "{err:?}");
+        }
+    }
+
+    #[test]
+    fn money_movement_is_retried_only_with_an_idempotency_key() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.for_money_movement(None).max_attempts, 1);
+        assert_eq!(policy.for_money_movement(Some("key")).max_attempts, 3);
+
+        let create = TransferCreateRequest::default();
+        assert_eq!(create.retry_policy(&policy).max_attempts, 1);
+
+        let create = TransferCreateRequest {
+            idempotency_key: Some("key".into()),
+            ..Default::default()
+        };
+        assert_eq!(create.retry_policy(&policy).max_attempts, 3);
+
+        let get = TransferGetRequest::default();
+        assert_eq!(get.retry_policy(&policy).max_attempts, 3);
+    }
+}
@@ -0,0 +1,1699 @@
+//! An in-memory stand-in for Plaid, for testing code written against the API
+//! traits without reaching the network. Only built with the `test-util` feature.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Mutex, MutexGuard},
+};
+
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDate, Utc};
+use rust_decimal::Decimal;
+
+use crate::{
+    error::PlaidError,
+    model::{
+        accounts::{
+            Account, AccountBalance, AccountItem, AccountType, AccountsBalanceGetRequest,
+            AccountsGetResponse,
+        },
+        auth::{AchNumbers, AuthGetRequest, AuthGetResponse, AuthNumbers},
+        common::{Amount, Currency, IsoCurrency, Product},
+        error::{PlaidErrorCode, PlaidErrorResponse, PlaidErrorType},
+        identity::{
+            AccountWithMatchScores, AccountWithOwners, AddressMatchScore, IdentityGetRequest,
+            IdentityGetResponse, IdentityMatchRequest, IdentityMatchResponse, MatchScore,
+            NameMatchScore, Owner,
+        },
+        institutions::{
+            Institution, InstitutionsGetByIdRequest, InstitutionsGetByIdResponse,
+            InstitutionsGetRequest, InstitutionsGetResponse, InstitutionsSearchRequest,
+            InstitutionsSearchResponse,
+        },
+        items::{
+            ItemAccessTokenInvalidateRequest, ItemAccessTokenInvalidateResponse, ItemGetRequest,
+            ItemGetResponse, ItemPublicTokenExchangeRequest, ItemPublicTokenExchangeResponse,
+            ItemRemoveRequest, ItemRemoveResponse, ItemWebhookUpdateRequest,
+            ItemWebhookUpdateResponse,
+        },
+        link::{LinkTokenCreateRequest, LinkTokenCreateResponse},
+        sandbox::{
+            SandboxItemFireWebhookRequest, SandboxItemFireWebhookResponse,
+            SandboxItemResetLoginRequest, SandboxItemResetLoginResponse,
+            SandboxPublicTokenCreateRequest, SandboxPublicTokenCreateResponse,
+            SandboxTransferSimulateRequest, SandboxTransferSimulateResponse,
+        },
+        secret::{AccessToken, LinkToken, PublicToken},
+        transactions::{
+            PaymentChannel, Transaction, TransactionsSyncRequest, TransactionsSyncResponse,
+            TransactionsUpdateStatus,
+        },
+        transfer::{
+            Transfer, TransferAuthorization, TransferAuthorizationCreateRequest,
+            TransferAuthorizationCreateResponse, TransferAuthorizationDecision,
+            TransferAuthorizationDecisionRationale, TransferAuthorizationDecisionRationaleCode,
+            TransferAuthorizationProposedTransfer, TransferCancelRequest, TransferCancelResponse,
+            TransferCreateRequest, TransferCreateResponse, TransferEvent, TransferEventListRequest,
+            TransferEventListResponse, TransferEventSyncRequest, TransferEventSyncResponse,
+            TransferEventType, TransferFailure, TransferGetRequest, TransferGetResponse,
+            TransferListRequest, TransferListResponse, TransferStatus, TransferSweepStatus,
+            TransferType,
+        },
+    },
+    traits::{
+        AccountsApi, AuthApi, Endpoint, IdentityApi, InstitutionsApi, ItemsApi, LinkApi,
+        SandboxApi, TransactionsApi, TransferApi,
+    },
+    PlaidResult,
+};
+
+/// First Platypus Bank, the default sandbox institution
+pub const FAKE_INSTITUTION_ID: &str = "ins_109508";
+
+/// An item as the fake stores it, together with the data of its accounts
+#[derive(Debug, Clone, PartialEq)]
+pub struct FakeItem {
+    pub item: AccountItem,
+    pub accounts: Vec<Account>,
+    /// Returned by `/auth/get`
+    pub numbers: AuthNumbers,
+    /// The owners of every account, returned by `/identity/get`
+    pub owners: Vec<Owner>,
+    /// Paged through by `/transactions/sync` in order
+    pub transactions: Vec<Transaction>,
+}
+
+impl FakeItem {
+    /// An item at `institution_id` without any accounts. The item id is
+    /// assigned once the item is handed to [`FakePlaid`].
+    pub fn new(institution_id: &str) -> Self {
+        Self {
+            item: AccountItem {
+                item_id: String::new(),
+                institution_id: Some(institution_id.to_string()),
+                webhook: None,
+                error: None,
+                available_products: vec![Product::Balance],
+                billed_products: Some(vec![Product::Auth, Product::Transactions]),
+                consented_products: None,
+                consent_expiration_time: None,
+                updated_type: None,
+            },
+            accounts: Vec::new(),
+            numbers: AuthNumbers::default(),
+            owners: Vec::new(),
+            transactions: Vec::new(),
+        }
+    }
+
+    pub fn with_account(mut self, account: Account) -> Self {
+        self.accounts.push(account);
+        self
+    }
+
+    pub fn with_transaction(mut self, transaction: Transaction) -> Self {
+        self.transactions.push(transaction);
+        self
+    }
+}
+
+/// A checking and a savings account at [`FAKE_INSTITUTION_ID`], in USD and
+/// with ACH numbers
+impl Default for FakeItem {
+    fn default() -> Self {
+        let mut item = Self::new(FAKE_INSTITUTION_ID)
+            .with_account(depository_account(
+                "checking-0000",
+                "Plaid Checking",
+                "checking",
+                Amount::new(Decimal::new(11000, 2)),
+            ))
+            .with_account(depository_account(
+                "savings-1111",
+                "Plaid Saving",
+                "savings",
+                Amount::new(Decimal::new(21000, 2)),
+            ));
+        item.numbers.ach = vec![
+            ach_numbers("checking-0000", "1111222233330000"),
+            ach_numbers("savings-1111", "1111222233331111"),
+        ];
+        item
+    }
+}
+
+fn ach_numbers(account_id: &str, account: &str) -> AchNumbers {
+    AchNumbers {
+        account_id: account_id.to_string(),
+        account: account.to_string(),
+        routing: "011401533".to_string(),
+        wire_routing: Some("021000021".to_string()),
+        can_transfer_in: Some(true),
+        can_transfer_out: Some(true),
+    }
+}
+
+/// A USD depository account masked with the last four characters of
+/// `account_id`, whose available and current balances are both `balance`
+pub fn depository_account(account_id: &str, name: &str, subtype: &str, balance: Amount) -> Account {
+    Account {
+        account_id: account_id.to_string(),
+        balances: AccountBalance {
+            available: Some(balance),
+            current: Some(balance),
+            limit: None,
+            currency: Some(Currency::Iso(IsoCurrency::Usd)),
+            last_updated_datetime: None,
+        },
+        mask: Some(account_id[account_id.len().saturating_sub(4)..].to_string()),
+        name: name.to_string(),
+        official_name: None,
+        type_: AccountType::Depository,
+        subtype: Some(subtype.to_string()),
+        verification_status: None,
+        verification_insights: None,
+        persistent_account_id: format!("persistent-{account_id}"),
+        holder_category: None,
+    }
+}
+
+/// A posted, online USD transaction dated 2024-01-01
+pub fn transaction(transaction_id: &str, account_id: &str, amount: Amount) -> Transaction {
+    Transaction {
+        transaction_id: transaction_id.to_string(),
+        account_id: account_id.to_string(),
+        amount,
+        currency: Some(Currency::Iso(IsoCurrency::Usd)),
+        date: NaiveDate::from_ymd_opt(2024, 1, 1).expect("valid date"),
+        authorized_date: None,
+        datetime: None,
+        authorized_datetime: None,
+        name: format!("Transaction {transaction_id}"),
+        merchant_name: None,
+        merchant_entity_id: None,
+        logo_url: None,
+        website: None,
+        original_description: None,
+        pending: false,
+        pending_transaction_id: None,
+        account_owner: None,
+        payment_channel: PaymentChannel::Online,
+        transaction_code: None,
+        personal_finance_category: None,
+        personal_finance_category_icon_url: None,
+        location: None,
+        check_number: None,
+    }
+}
+
+/// Builds the error Plaid would send for `error_code` of `error_type`
+pub fn plaid_error(
+    error_type: PlaidErrorType,
+    error_code: &str,
+    message: &str,
+) -> PlaidErrorResponse {
+    let status = match error_type {
+        PlaidErrorType::ApiError => 500,
+        PlaidErrorType::RateLimitExceeded => 429,
+        _ => 400,
+    };
+
+    PlaidErrorResponse {
+        causes: None,
+        display_message: None,
+        documentation_url: "https://plaid.com/docs/errors/".to_string(),
+        error_code: PlaidErrorCode::from_parts(&error_type, error_code),
+        error_code_reason: None,
+        error_message: message.to_string(),
+        error_type,
+        request_id: "fake-request".to_string(),
+        status: Some(status),
+        suggested_action: None,
+    }
+}
+
+#[derive(Debug, Default)]
+struct FakeState {
+    counter: u64,
+    /// Keyed by access token
+    items: HashMap<String, FakeItem>,
+    /// Items waiting for their public token to be exchanged
+    public_tokens: HashMap<String, FakeItem>,
+    institutions: Vec<Institution>,
+    authorizations: Vec<TransferAuthorization>,
+    /// Authorizations that a transfer has been created from
+    used_authorizations: Vec<String>,
+    transfers: Vec<Transfer>,
+    transfer_events: Vec<TransferEvent>,
+    /// Ids of what was created under an idempotency key, keyed by endpoint and key
+    idempotent: HashMap<(&'static str, String), String>,
+    failures: HashMap<&'static str, VecDeque<PlaidErrorResponse>>,
+    calls: Vec<&'static str>,
+}
+
+fn api_error(error_type: PlaidErrorType, error_code: &str, message: &str) -> PlaidError {
+    PlaidError::Api(Box::new(plaid_error(error_type, error_code, message)))
+}
+
+fn invalid_access_token() -> PlaidError {
+    api_error(
+        PlaidErrorType::InvalidInput,
+        "INVALID_ACCESS_TOKEN",
+        "provided access token is in an invalid format",
+    )
+}
+
+/// Whether `account_id` is among the `account_ids` a request is limited to
+fn is_selected(account_ids: &Option<Vec<String>>, account_id: &str) -> bool {
+    match account_ids {
+        Some(ids) if !ids.is_empty() => ids.iter().any(|id| id == account_id),
+        _ => true,
+    }
+}
+
+/// The page starting at `offset` of at most `count` elements, and whether
+/// any elements follow it
+fn page<T: Clone>(items: &[T], offset: usize, count: usize) -> (Vec<T>, bool) {
+    let start = offset.min(items.len());
+    let end = start.saturating_add(count).min(items.len());
+    (items[start..end].to_vec(), end < items.len())
+}
+
+fn match_score(matched: Option<bool>) -> Option<u8> {
+    matched.map(|matched| if matched { 100 } else { 0 })
+}
+
+impl FakeState {
+    fn next(&mut self) -> u64 {
+        self.counter += 1;
+        self.counter
+    }
+
+    fn request_id(&mut self) -> String {
+        format!("fake-request-{}", self.next())
+    }
+
+    fn access_token(&mut self) -> AccessToken {
+        AccessToken::new(format!("access-sandbox-fake-{}", self.next()))
+    }
+
+    fn assign_item_id(&mut self, item: &mut FakeItem) {
+        if item.item.item_id.is_empty() {
+            item.item.item_id = format!("fake-item-{}", self.next());
+        }
+    }
+
+    fn item_mut(&mut self, access_token: &AccessToken) -> PlaidResult<&mut FakeItem> {
+        self.items
+            .get_mut(access_token.expose_secret())
+            .ok_or_else(invalid_access_token)
+    }
+
+    /// Unlinks the item from `access_token` and hands it back
+    fn take_item(&mut self, access_token: &AccessToken) -> PlaidResult<FakeItem> {
+        self.items
+            .remove(access_token.expose_secret())
+            .ok_or_else(invalid_access_token)
+    }
+
+    /// The item, unless its `error` keeps its data from being read
+    fn linked_item(&mut self, access_token: &AccessToken) -> PlaidResult<&mut FakeItem> {
+        let item = self.item_mut(access_token)?;
+        match &item.item.error {
+            Some(error) => Err(PlaidError::Api(Box::new(error.clone()))),
+            None => Ok(item),
+        }
+    }
+
+    fn transfer_mut(&mut self, transfer_id: &str) -> PlaidResult<&mut Transfer> {
+        self.transfers
+            .iter_mut()
+            .find(|transfer| transfer.id == transfer_id)
+            .ok_or_else(|| {
+                api_error(
+                    PlaidErrorType::InvalidRequest,
+                    "NOT_FOUND",
+                    "transfer not found",
+                )
+            })
+    }
+
+    fn push_event(
+        &mut self,
+        transfer_id: &str,
+        event_type: TransferEventType,
+        failure_reason: Option<TransferFailure>,
+    ) {
+        let transfer = self
+            .transfers
+            .iter()
+            .find(|transfer| transfer.id == transfer_id)
+            .expect("events are only pushed for stored transfers");
+        let event = TransferEvent {
+            event_id: self.transfer_events.len() as u64 + 1,
+            timestamp: Utc::now(),
+            event_type,
+            account_id: transfer.account_id.clone(),
+            funding_account_id: transfer.funding_account_id.clone(),
+            transfer_id: transfer.id.clone(),
+            origination_account_id: None,
+            transfer_type: transfer.type_.clone(),
+            transfer_amount: transfer.amount,
+            failure_reason,
+            sweep_id: None,
+            sweep_amount: None,
+            refund_id: None,
+            originator_client_id: transfer.originator_client_id.clone(),
+        };
+        self.transfer_events.push(event);
+    }
+}
+
+/// Implements the API traits over scriptable in-memory state.
+///
+/// Every call is recorded (see [`FakePlaid::calls`]) and can be made to fail
+/// with [`FakePlaid::fail_next`]. Items carrying an `error` fail the calls
+/// that read their data, like Plaid does for `ITEM_LOGIN_REQUIRED`.
+#[derive(Debug, Default)]
+pub struct FakePlaid {
+    state: Mutex<FakeState>,
+}
+
+impl FakePlaid {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores an already linked item and returns its access token
+    pub fn add_item(&self, mut item: FakeItem) -> AccessToken {
+        let mut state = self.lock();
+        state.assign_item_id(&mut item);
+        let access_token = state.access_token();
+        state
+            .items
+            .insert(access_token.expose_secret().to_string(), item);
+        access_token
+    }
+
+    /// Stores an item that becomes linked once the returned public token is exchanged
+    pub fn create_public_token(&self, mut item: FakeItem) -> PublicToken {
+        let mut state = self.lock();
+        state.assign_item_id(&mut item);
+        let public_token = format!("public-sandbox-fake-{}", state.next());
+        state.public_tokens.insert(public_token.clone(), item);
+        PublicToken::new(public_token)
+    }
+
+    pub fn item(&self, access_token: &AccessToken) -> Option<FakeItem> {
+        self.lock().items.get(access_token.expose_secret()).cloned()
+    }
+
+    /// # Panics
+    ///
+    /// If no item is linked under `access_token`
+    pub fn update_item<F>(&self, access_token: &AccessToken, update: F)
+    where
+        F: FnOnce(&mut FakeItem),
+    {
+        let mut state = self.lock();
+        let item = state
+            .items
+            .get_mut(access_token.expose_secret())
+            .expect("no fake item for this access token");
+        update(item);
+    }
+
+    /// # Panics
+    ///
+    /// If the item or the account does not exist
+    pub fn set_balance(
+        &self,
+        access_token: &AccessToken,
+        account_id: &str,
+        balance: AccountBalance,
+    ) {
+        self.update_item(access_token, |item| {
+            let account = item
+                .accounts
+                .iter_mut()
+                .find(|account| account.account_id == account_id)
+                .expect("no fake account with this id");
+            account.balances = balance;
+        });
+    }
+
+    /// Makes the institution available to the `/institutions` endpoints
+    pub fn add_institution(&self, institution: Institution) {
+        self.lock().institutions.push(institution);
+    }
+
+    pub fn transfer(&self, transfer_id: &str) -> Option<Transfer> {
+        self.lock()
+            .transfers
+            .iter()
+            .find(|transfer| transfer.id == transfer_id)
+            .cloned()
+    }
+
+    /// Makes the next call to `E`'s endpoint fail with `error`. Failures
+    /// queued for the same endpoint are returned in order.
+    pub fn fail_next<E: Endpoint>(&self, error: PlaidErrorResponse) {
+        self.lock()
+            .failures
+            .entry(E::PATH)
+            .or_default()
+            .push_back(error);
+    }
+
+    /// Paths of the endpoints called so far, in order
+    pub fn calls(&self) -> Vec<&'static str> {
+        self.lock().calls.clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, FakeState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Records the call to `E` and returns the failure queued for it, if any
+    fn begin<E: Endpoint>(&self) -> PlaidResult<MutexGuard<'_, FakeState>> {
+        let mut state = self.lock();
+        state.calls.push(E::PATH);
+
+        match state
+            .failures
+            .get_mut(E::PATH)
+            .and_then(VecDeque::pop_front)
+        {
+            Some(error) => Err(PlaidError::Api(Box::new(error))),
+            None => Ok(state),
+        }
+    }
+}
+
+#[async_trait]
+impl LinkApi for FakePlaid {
+    async fn link_token_create(
+        &self,
+        _req: LinkTokenCreateRequest,
+    ) -> PlaidResult<LinkTokenCreateResponse> {
+        let mut state = self.begin::<LinkTokenCreateRequest>()?;
+        let link_token = format!("link-sandbox-fake-{}", state.next());
+
+        Ok(LinkTokenCreateResponse {
+            expiration: Utc::now() + Duration::hours(4),
+            link_token: LinkToken::new(link_token),
+            request_id: state.request_id(),
+            hosted_link_url: None,
+        })
+    }
+}
+
+#[async_trait]
+impl ItemsApi for FakePlaid {
+    async fn item_public_token_exchange(
+        &self,
+        req: ItemPublicTokenExchangeRequest,
+    ) -> PlaidResult<ItemPublicTokenExchangeResponse> {
+        let mut state = self.begin::<ItemPublicTokenExchangeRequest>()?;
+        let item = state
+            .public_tokens
+            .remove(req.public_token.expose_secret())
+            .ok_or_else(|| {
+                api_error(
+                    PlaidErrorType::InvalidInput,
+                    "INVALID_PUBLIC_TOKEN",
+                    "provided public token is expired or has already been exchanged",
+                )
+            })?;
+
+        let item_id = item.item.item_id.clone();
+        let access_token = state.access_token();
+        state
+            .items
+            .insert(access_token.expose_secret().to_string(), item);
+
+        Ok(ItemPublicTokenExchangeResponse {
+            access_token,
+            item_id,
+            request_id: state.request_id(),
+        })
+    }
+
+    async fn item_get(&self, req: ItemGetRequest) -> PlaidResult<ItemGetResponse> {
+        let mut state = self.begin::<ItemGetRequest>()?;
+        let item = state.item_mut(&req.access_token)?.item.clone();
+
+        Ok(ItemGetResponse {
+            item,
+            status: None,
+            request_id: state.request_id(),
+        })
+    }
+
+    async fn item_remove(&self, req: ItemRemoveRequest) -> PlaidResult<ItemRemoveResponse> {
+        let mut state = self.begin::<ItemRemoveRequest>()?;
+        state.take_item(&req.access_token)?;
+
+        Ok(ItemRemoveResponse {
+            request_id: state.request_id(),
+        })
+    }
+
+    async fn item_webhook_update(
+        &self,
+        req: ItemWebhookUpdateRequest,
+    ) -> PlaidResult<ItemWebhookUpdateResponse> {
+        let mut state = self.begin::<ItemWebhookUpdateRequest>()?;
+        let item = state.item_mut(&req.access_token)?;
+        item.item.webhook = req.webhook;
+        let item = item.item.clone();
+
+        Ok(ItemWebhookUpdateResponse {
+            item,
+            request_id: state.request_id(),
+        })
+    }
+
+    async fn item_access_token_invalidate(
+        &self,
+        req: ItemAccessTokenInvalidateRequest,
+    ) -> PlaidResult<ItemAccessTokenInvalidateResponse> {
+        let mut state = self.begin::<ItemAccessTokenInvalidateRequest>()?;
+        let item = state.take_item(&req.access_token)?;
+        let new_access_token = state.access_token();
+        state
+            .items
+            .insert(new_access_token.expose_secret().to_string(), item);
+
+        Ok(ItemAccessTokenInvalidateResponse {
+            new_access_token,
+            request_id: state.request_id(),
+        })
+    }
+}
+
+#[async_trait]
+impl AccountsApi for FakePlaid {
+    async fn accounts_balance_get(
+        &self,
+        req: AccountsBalanceGetRequest,
+    ) -> PlaidResult<AccountsGetResponse> {
+        let mut state = self.begin::<AccountsBalanceGetRequest>()?;
+        let item = state.linked_item(&req.access_token)?;
+
+        let account_ids = req.options.map(|options| options.account_ids);
+        let accounts = item
+            .accounts
+            .iter()
+            .filter(|account| is_selected(&account_ids, &account.account_id))
+            .cloned()
+            .collect();
+        let item = item.item.clone();
+
+        Ok(AccountsGetResponse {
+            accounts,
+            item,
+            request_id: state.request_id(),
+        })
+    }
+}
+
+#[async_trait]
+impl SandboxApi for FakePlaid {
+    /// Creates a [`FakeItem::default`] at the requested institution
+    async fn sandbox_public_token_create(
+        &self,
+        req: SandboxPublicTokenCreateRequest,
+    ) -> PlaidResult<SandboxPublicTokenCreateResponse> {
+        drop(self.begin::<SandboxPublicTokenCreateRequest>()?);
+
+        let mut item = FakeItem::default();
+        item.item.institution_id = Some(req.institution_id);
+        item.item.billed_products = Some(req.initial_products);
+        item.item.webhook = req.options.and_then(|options| options.webhook);
+        let public_token = self.create_public_token(item);
+
+        Ok(SandboxPublicTokenCreateResponse {
+            public_token,
+            request_id: self.lock().request_id(),
+        })
+    }
+
+    /// Puts the item into `ITEM_LOGIN_REQUIRED`
+    async fn sandbox_item_reset_login(
+        &self,
+        req: SandboxItemResetLoginRequest,
+    ) -> PlaidResult<SandboxItemResetLoginResponse> {
+        let mut state = self.begin::<SandboxItemResetLoginRequest>()?;
+        state.item_mut(&req.access_token)?.item.error = Some(plaid_error(
+            PlaidErrorType::ItemError,
+            "ITEM_LOGIN_REQUIRED",
+            "the login details of this item have changed",
+        ));
+
+        Ok(SandboxItemResetLoginResponse {
+            reset_login: true,
+            request_id: state.request_id(),
+        })
+    }
+
+    /// Does not deliver anything; only checks the item exists
+    async fn sandbox_item_fire_webhook(
+        &self,
+        req: SandboxItemFireWebhookRequest,
+    ) -> PlaidResult<SandboxItemFireWebhookResponse> {
+        let mut state = self.begin::<SandboxItemFireWebhookRequest>()?;
+        state.item_mut(&req.access_token)?;
+
+        Ok(SandboxItemFireWebhookResponse {
+            webhook_fired: true,
+            request_id: state.request_id(),
+        })
+    }
+
+    /// Moves the transfer along the lifecycle Plaid allows, e.g. `posted`
+    /// only from `pending`, and records the matching event
+    async fn sandbox_transfer_simulate(
+        &self,
+        req: SandboxTransferSimulateRequest,
+    ) -> PlaidResult<SandboxTransferSimulateResponse> {
+        let mut state = self.begin::<SandboxTransferSimulateRequest>()?;
+        let transfer = state.transfer_mut(&req.transfer_id)?;
+
+        let status = match (&req.event_type, &transfer.status) {
+            (TransferEventType::Posted, TransferStatus::Pending) => TransferStatus::Posted,
+            (TransferEventType::Settled, TransferStatus::Posted) => TransferStatus::Settled,
+            (TransferEventType::FundsAvailable, TransferStatus::Settled) => {
+                TransferStatus::FundsAvailable
+            }
+            (TransferEventType::Failed, TransferStatus::Pending) => TransferStatus::Failed,
+            (
+                TransferEventType::Returned,
+                TransferStatus::Posted | TransferStatus::Settled | TransferStatus::FundsAvailable,
+            ) => TransferStatus::Returned,
+            _ => {
+                return Err(api_error(
+                    PlaidErrorType::SandboxError,
+                    "SANDBOX_TRANSFER_EVENT_TRANSITION_INVALID",
+                    "the transfer cannot move to this status from its current one",
+                ))
+            }
+        };
+        transfer.status = status;
+        transfer.cancellable = false;
+        transfer.failure_reason = req.failure_reason.clone();
+
+        state.push_event(&req.transfer_id, req.event_type, req.failure_reason);
+
+        Ok(SandboxTransferSimulateResponse {
+            request_id: state.request_id(),
+        })
+    }
+}
+
+#[async_trait]
+impl AuthApi for FakePlaid {
+    async fn auth_get(&self, req: AuthGetRequest) -> PlaidResult<AuthGetResponse> {
+        let mut state = self.begin::<AuthGetRequest>()?;
+        let item = state.linked_item(&req.access_token)?;
+
+        let account_ids = req.options.map(|options| options.account_ids);
+        let selected = |account_id: &str| is_selected(&account_ids, account_id);
+        let accounts = item
+            .accounts
+            .iter()
+            .filter(|account| selected(&account.account_id))
+            .cloned()
+            .collect();
+        let numbers = AuthNumbers {
+            ach: item
+                .numbers
+                .ach
+                .iter()
+                .filter(|numbers| selected(&numbers.account_id))
+                .cloned()
+                .collect(),
+            eft: item
+                .numbers
+                .eft
+                .iter()
+                .filter(|numbers| selected(&numbers.account_id))
+                .cloned()
+                .collect(),
+            international: item
+                .numbers
+                .international
+                .iter()
+                .filter(|numbers| selected(&numbers.account_id))
+                .cloned()
+                .collect(),
+            bacs: item
+                .numbers
+                .bacs
+                .iter()
+                .filter(|numbers| selected(&numbers.account_id))
+                .cloned()
+                .collect(),
+        };
+        let item = item.item.clone();
+
+        Ok(AuthGetResponse {
+            accounts,
+            numbers,
+            item,
+            request_id: state.request_id(),
+        })
+    }
+}
+
+#[async_trait]
+impl IdentityApi for FakePlaid {
+    async fn identity_get(&self, req: IdentityGetRequest) -> PlaidResult<IdentityGetResponse> {
+        let mut state = self.begin::<IdentityGetRequest>()?;
+        let item = state.linked_item(&req.access_token)?;
+
+        let account_ids = req.options.map(|options| options.account_ids);
+        let accounts = item
+            .accounts
+            .iter()
+            .filter(|account| is_selected(&account_ids, &account.account_id))
+            .map(|account| AccountWithOwners {
+                account: account.clone(),
+                owners: item.owners.clone(),
+            })
+            .collect();
+        let item = item.item.clone();
+
+        Ok(IdentityGetResponse {
+            accounts,
+            item,
+            request_id: state.request_id(),
+        })
+    }
+
+    /// Scores 100 for an exact (case-insensitive) match with any owner and 0
+    /// otherwise; fields missing from the request are not scored
+    async fn identity_match(
+        &self,
+        req: IdentityMatchRequest,
+    ) -> PlaidResult<IdentityMatchResponse> {
+        let mut state = self.begin::<IdentityMatchRequest>()?;
+        let item = state.linked_item(&req.access_token)?;
+        let user = &req.user;
+        let owners = &item.owners;
+
+        let legal_name = user.legal_name.as_ref().map(|legal_name| {
+            owners
+                .iter()
+                .flat_map(|owner| &owner.names)
+                .any(|name| name.eq_ignore_ascii_case(legal_name))
+        });
+        let phone_number = user.phone_number.as_ref().map(|phone_number| {
+            owners
+                .iter()
+                .flat_map(|owner| &owner.phone_numbers)
+                .any(|number| number.e164().as_ref() == Some(phone_number))
+        });
+        let email_address = user.email_address.as_ref().map(|email_address| {
+            owners
+                .iter()
+                .flat_map(|owner| &owner.emails)
+                .any(|email| email.data.eq_ignore_ascii_case(email_address))
+        });
+        let address = user.address.as_ref().map(|address| {
+            let mut addresses = owners.iter().flat_map(|owner| &owner.addresses);
+            let postal_code = addresses
+                .clone()
+                .any(|owned| owned.data.postal_code == address.postal_code);
+            let full = addresses.any(|owned| {
+                owned.data.postal_code == address.postal_code
+                    && owned.data.street.as_ref().map(|s| s.to_lowercase())
+                        == address.street.as_ref().map(|s| s.to_lowercase())
+            });
+            (full, postal_code)
+        });
+
+        let account_ids = req.options.map(|options| options.account_ids);
+        let accounts = item
+            .accounts
+            .iter()
+            .filter(|account| is_selected(&account_ids, &account.account_id))
+            .map(|account| AccountWithMatchScores {
+                account: account.clone(),
+                legal_name: legal_name.map(|matched| NameMatchScore {
+                    score: match_score(Some(matched)),
+                    is_first_name_or_last_name_match: None,
+                    is_nickname_match: None,
+                    is_business_name_detected: Some(false),
+                }),
+                phone_number: phone_number.map(|matched| MatchScore {
+                    score: match_score(Some(matched)),
+                }),
+                email_address: email_address.map(|matched| MatchScore {
+                    score: match_score(Some(matched)),
+                }),
+                address: address.map(|(matched, postal_code)| AddressMatchScore {
+                    score: match_score(Some(matched)),
+                    is_postal_code_match: Some(postal_code),
+                }),
+            })
+            .collect();
+        let item = item.item.clone();
+
+        Ok(IdentityMatchResponse {
+            accounts,
+            item,
+            request_id: state.request_id(),
+        })
+    }
+}
+
+#[async_trait]
+impl InstitutionsApi for FakePlaid {
+    async fn institutions_get(
+        &self,
+        req: InstitutionsGetRequest,
+    ) -> PlaidResult<InstitutionsGetResponse> {
+        let mut state = self.begin::<InstitutionsGetRequest>()?;
+        let options = req.options.unwrap_or_default();
+
+        let matching: Vec<Institution> = state
+            .institutions
+            .iter()
+            .filter(|institution| {
+                req.country_codes
+                    .iter()
+                    .any(|country| institution.country_codes.contains(country))
+            })
+            .filter(|institution| {
+                options.products.as_ref().map_or(true, |products| {
+                    products
+                        .iter()
+                        .all(|product| institution.products.contains(product))
+                })
+            })
+            .filter(|institution| {
+                options.routing_numbers.as_ref().map_or(true, |numbers| {
+                    numbers
+                        .iter()
+                        .any(|number| institution.routing_numbers.contains(number))
+                })
+            })
+            .filter(|institution| {
+                options
+                    .oauth
+                    .map_or(true, |oauth| institution.oauth == oauth)
+            })
+            .cloned()
+            .collect();
+        let (institutions, _) = page(&matching, req.offset as usize, req.count as usize);
+
+        Ok(InstitutionsGetResponse {
+            institutions,
+            total: matching.len() as u32,
+            request_id: state.request_id(),
+        })
+    }
+
+    /// Matches `query` case-insensitively anywhere in the institution name
+    async fn institutions_search(
+        &self,
+        req: InstitutionsSearchRequest,
+    ) -> PlaidResult<InstitutionsSearchResponse> {
+        let mut state = self.begin::<InstitutionsSearchRequest>()?;
+        let query = req.query.to_lowercase();
+
+        let institutions = state
+            .institutions
+            .iter()
+            .filter(|institution| institution.name.to_lowercase().contains(&query))
+            .filter(|institution| {
+                req.country_codes
+                    .iter()
+                    .any(|country| institution.country_codes.contains(country))
+            })
+            .filter(|institution| {
+                req.products.as_ref().map_or(true, |products| {
+                    products
+                        .iter()
+                        .all(|product| institution.products.contains(product))
+                })
+            })
+            .cloned()
+            .collect();
+
+        Ok(InstitutionsSearchResponse {
+            institutions,
+            request_id: state.request_id(),
+        })
+    }
+
+    async fn institutions_get_by_id(
+        &self,
+        req: InstitutionsGetByIdRequest,
+    ) -> PlaidResult<InstitutionsGetByIdResponse> {
+        let mut state = self.begin::<InstitutionsGetByIdRequest>()?;
+        let institution = state
+            .institutions
+            .iter()
+            .find(|institution| institution.institution_id == req.institution_id)
+            .cloned()
+            .ok_or_else(|| {
+                api_error(
+                    PlaidErrorType::InvalidInput,
+                    "INVALID_INSTITUTION",
+                    "invalid institution_id provided",
+                )
+            })?;
+
+        Ok(InstitutionsGetByIdResponse {
+            institution,
+            request_id: state.request_id(),
+        })
+    }
+}
+
+#[async_trait]
+impl TransactionsApi for FakePlaid {
+    /// Pages through the item's `transactions`, all of them reported as
+    /// added; the cursor is the index of the next transaction
+    async fn transactions_sync(
+        &self,
+        req: TransactionsSyncRequest,
+    ) -> PlaidResult<TransactionsSyncResponse> {
+        let mut state = self.begin::<TransactionsSyncRequest>()?;
+        let item = state.linked_item(&req.access_token)?;
+
+        let offset = match req.cursor.as_deref() {
+            None | Some("") => 0,
+            Some(cursor) => cursor.parse().map_err(|_| {
+                api_error(
+                    PlaidErrorType::InvalidRequest,
+                    "INVALID_FIELD",
+                    "cursor is not a valid cursor",
+                )
+            })?,
+        };
+        let count = req.count.unwrap_or(100) as usize;
+        let (added, has_more) = page(&item.transactions, offset, count);
+        let next_cursor = (offset + added.len()).to_string();
+        let accounts = item.accounts.clone();
+
+        Ok(TransactionsSyncResponse {
+            transactions_update_status: Some(TransactionsUpdateStatus::HistoricalUpdateComplete),
+            accounts: Some(accounts),
+            added,
+            modified: Vec::new(),
+            removed: Vec::new(),
+            next_cursor,
+            has_more,
+            request_id: state.request_id(),
+        })
+    }
+}
+
+#[async_trait]
+impl TransferApi for FakePlaid {
+    /// Debits are declined with `NSF` when they exceed the account's
+    /// available (or else current) balance; everything else is approved
+    async fn transfer_authorization_create(
+        &self,
+        req: TransferAuthorizationCreateRequest,
+    ) -> PlaidResult<TransferAuthorizationCreateResponse> {
+        let mut state = self.begin::<TransferAuthorizationCreateRequest>()?;
+        let path = TransferAuthorizationCreateRequest::PATH;
+
+        let replayed = req
+            .idempotency_key
+            .clone()
+            .and_then(|key| state.idempotent.get(&(path, key)).cloned())
+            .and_then(|id| {
+                state
+                    .authorizations
+                    .iter()
+                    .find(|authorization| authorization.id == id)
+                    .cloned()
+            });
+        if let Some(authorization) = replayed {
+            return Ok(TransferAuthorizationCreateResponse {
+                authorization,
+                request_id: state.request_id(),
+            });
+        }
+
+        let account = state
+            .linked_item(&req.access_token)?
+            .accounts
+            .iter()
+            .find(|account| account.account_id == req.account_id)
+            .cloned()
+            .ok_or_else(|| {
+                api_error(
+                    PlaidErrorType::InvalidInput,
+                    "INVALID_ACCOUNT_ID",
+                    "account_id is not an account of this item",
+                )
+            })?;
+        let balance = account
+            .balances
+            .available
+            .or(account.balances.current)
+            .unwrap_or(Amount::ZERO);
+        let declined = req.type_ == TransferType::Debit && req.amount > balance;
+
+        let id = format!("fake-authorization-{}", state.next());
+        let authorization = TransferAuthorization {
+            id: id.clone(),
+            created: Utc::now(),
+            decision: if declined {
+                TransferAuthorizationDecision::Declined
+            } else {
+                TransferAuthorizationDecision::Approved
+            },
+            decision_rationale: declined.then(|| TransferAuthorizationDecisionRationale {
+                code: TransferAuthorizationDecisionRationaleCode::Nsf,
+                description: "the account balance does not cover the transfer".to_string(),
+            }),
+            proposed_transfer: TransferAuthorizationProposedTransfer {
+                ach_class: req.ach_class,
+                account_id: Some(req.account_id),
+                funding_account_id: None,
+                type_: req.type_,
+                user: req.user,
+                amount: req.amount,
+                network: req.network,
+                iso_currency_code: req.iso_currency_code.unwrap_or_else(|| "USD".to_string()),
+                originator_client_id: None,
+            },
+        };
+        state.authorizations.push(authorization.clone());
+        if let Some(key) = req.idempotency_key {
+            state.idempotent.insert((path, key), id);
+        }
+
+        Ok(TransferAuthorizationCreateResponse {
+            authorization,
+            request_id: state.request_id(),
+        })
+    }
+
+    async fn transfer_create(
+        &self,
+        req: TransferCreateRequest,
+    ) -> PlaidResult<TransferCreateResponse> {
+        let mut state = self.begin::<TransferCreateRequest>()?;
+        let path = TransferCreateRequest::PATH;
+
+        let replayed = req
+            .idempotency_key
+            .clone()
+            .and_then(|key| state.idempotent.get(&(path, key)).cloned())
+            .and_then(|id| {
+                state
+                    .transfers
+                    .iter()
+                    .find(|transfer| transfer.id == id)
+                    .cloned()
+            });
+        if let Some(transfer) = replayed {
+            return Ok(TransferCreateResponse {
+                transfer,
+                request_id: state.request_id(),
+            });
+        }
+
+        state.linked_item(&req.access_token)?;
+        let invalid =
+            |message: &str| api_error(PlaidErrorType::InvalidRequest, "INVALID_FIELD", message);
+        let authorization = state
+            .authorizations
+            .iter()
+            .find(|authorization| authorization.id == req.authorization_id)
+            .cloned()
+            .ok_or_else(|| invalid("authorization_id not found"))?;
+        let proposed = authorization.proposed_transfer;
+
+        if authorization.decision != TransferAuthorizationDecision::Approved {
+            return Err(invalid("the authorization was not approved"));
+        }
+        if state.used_authorizations.contains(&authorization.id) {
+            return Err(invalid("the authorization has already been used"));
+        }
+        if proposed.account_id.as_deref() != Some(req.account_id.as_str()) {
+            return Err(invalid("account_id does not match the authorization"));
+        }
+        let amount = req.amount.unwrap_or(proposed.amount);
+        if amount > proposed.amount {
+            return Err(invalid("amount exceeds the authorized amount"));
+        }
+
+        let id = format!("fake-transfer-{}", state.next());
+        let transfer = Transfer {
+            id: id.clone(),
+            authorization_id: authorization.id.clone(),
+            ach_class: proposed.ach_class,
+            account_id: Some(req.account_id),
+            funding_account_id: None,
+            type_: proposed.type_,
+            user: proposed.user,
+            amount,
+            description: req.description,
+            created: Utc::now(),
+            status: TransferStatus::Pending,
+            sweep_status: Some(TransferSweepStatus::Unswept),
+            network: proposed.network,
+            cancellable: true,
+            failure_reason: None,
+            metadata: req.metadata,
+            iso_currency_code: proposed.iso_currency_code,
+            standard_return_window: None,
+            unauthorized_return_window: None,
+            expected_settlement_date: None,
+            originator_client_id: None,
+        };
+        state.used_authorizations.push(authorization.id);
+        state.transfers.push(transfer.clone());
+        state.push_event(&id, TransferEventType::Pending, None);
+        if let Some(key) = req.idempotency_key {
+            state.idempotent.insert((path, key), id);
+        }
+
+        Ok(TransferCreateResponse {
+            transfer,
+            request_id: state.request_id(),
+        })
+    }
+
+    async fn transfer_get(&self, req: TransferGetRequest) -> PlaidResult<TransferGetResponse> {
+        let mut state = self.begin::<TransferGetRequest>()?;
+        let transfer = state.transfer_mut(&req.transfer_id)?.clone();
+
+        Ok(TransferGetResponse {
+            transfer,
+            request_id: state.request_id(),
+        })
+    }
+
+    /// Newest first, like Plaid
+    async fn transfer_list(&self, req: TransferListRequest) -> PlaidResult<TransferListResponse> {
+        let mut state = self.begin::<TransferListRequest>()?;
+
+        let matching: Vec<Transfer> = state
+            .transfers
+            .iter()
+            .rev()
+            .filter(|transfer| {
+                req.start_date
+                    .map_or(true, |start| transfer.created >= start)
+            })
+            .filter(|transfer| req.end_date.map_or(true, |end| transfer.created <= end))
+            .filter(|transfer| {
+                req.funding_account_id.is_none()
+                    || transfer.funding_account_id == req.funding_account_id
+            })
+            .filter(|transfer| {
+                req.originator_client_id.is_none()
+                    || transfer.originator_client_id == req.originator_client_id
+            })
+            .cloned()
+            .collect();
+        let (transfers, _) = page(
+            &matching,
+            req.offset.unwrap_or(0) as usize,
+            req.count.unwrap_or(25) as usize,
+        );
+
+        Ok(TransferListResponse {
+            transfers,
+            request_id: state.request_id(),
+        })
+    }
+
+    async fn transfer_cancel(
+        &self,
+        req: TransferCancelRequest,
+    ) -> PlaidResult<TransferCancelResponse> {
+        let mut state = self.begin::<TransferCancelRequest>()?;
+        let transfer = state.transfer_mut(&req.transfer_id)?;
+
+        if !transfer.cancellable {
+            return Err(api_error(
+                PlaidErrorType::TransferError,
+                "TRANSFER_NOT_CANCELLABLE",
+                "transfer is not cancellable",
+            ));
+        }
+        transfer.status = TransferStatus::Cancelled;
+        transfer.cancellable = false;
+        state.push_event(&req.transfer_id, TransferEventType::Cancelled, None);
+
+        Ok(TransferCancelResponse {
+            request_id: state.request_id(),
+        })
+    }
+
+    async fn transfer_event_list(
+        &self,
+        req: TransferEventListRequest,
+    ) -> PlaidResult<TransferEventListResponse> {
+        let mut state = self.begin::<TransferEventListRequest>()?;
+
+        let matching: Vec<TransferEvent> = state
+            .transfer_events
+            .iter()
+            .filter(|event| {
+                req.start_date
+                    .map_or(true, |start| event.timestamp >= start)
+            })
+            .filter(|event| req.end_date.map_or(true, |end| event.timestamp <= end))
+            .filter(|event| {
+                req.transfer_id
+                    .as_ref()
+                    .map_or(true, |id| &event.transfer_id == id)
+            })
+            .filter(|event| req.account_id.is_none() || event.account_id == req.account_id)
+            .filter(|event| {
+                req.transfer_type
+                    .as_ref()
+                    .map_or(true, |type_| &event.transfer_type == type_)
+            })
+            .filter(|event| {
+                req.event_types
+                    .as_ref()
+                    .map_or(true, |types| types.contains(&event.event_type))
+            })
+            .filter(|event| {
+                req.funding_account_id.is_none()
+                    || event.funding_account_id == req.funding_account_id
+            })
+            .filter(|event| {
+                req.originator_client_id.is_none()
+                    || event.originator_client_id == req.originator_client_id
+            })
+            .cloned()
+            .collect();
+        let (transfer_events, has_more) = page(
+            &matching,
+            req.offset.unwrap_or(0) as usize,
+            req.count.unwrap_or(25) as usize,
+        );
+
+        Ok(TransferEventListResponse {
+            transfer_events,
+            has_more,
+            request_id: state.request_id(),
+        })
+    }
+
+    async fn transfer_event_sync(
+        &self,
+        req: TransferEventSyncRequest,
+    ) -> PlaidResult<TransferEventSyncResponse> {
+        let mut state = self.begin::<TransferEventSyncRequest>()?;
+
+        let after: Vec<TransferEvent> = state
+            .transfer_events
+            .iter()
+            .filter(|event| event.event_id > req.after_id)
+            .cloned()
+            .collect();
+        let (transfer_events, has_more) = page(&after, 0, req.count.unwrap_or(100) as usize);
+
+        Ok(TransferEventSyncResponse {
+            transfer_events,
+            has_more,
+            request_id: state.request_id(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::transfer::{TransferNetwork, TransferUser};
+
+    fn error_code<T: std::fmt::Debug>(result: PlaidResult<T>) -> String {
+        match result {
+            Err(PlaidError::Api(failure)) => failure.error_code.as_str().to_string(),
+            other => panic!("expected a Plaid API error, got {other:?}"),
+        }
+    }
+
+    fn authorization_request(
+        access_token: &AccessToken,
+        amount: &str,
+        idempotency_key: Option<&str>,
+    ) -> TransferAuthorizationCreateRequest {
+        TransferAuthorizationCreateRequest {
+            access_token: access_token.clone(),
+            account_id: "checking-0000".to_string(),
+            type_: TransferType::Debit,
+            network: TransferNetwork::Ach,
+            amount: amount.parse().unwrap(),
+            ach_class: None,
+            user: TransferUser {
+                legal_name: "Alberta Charleson".to_string(),
+                ..Default::default()
+            },
+            device: None,
+            idempotency_key: idempotency_key.map(str::to_string),
+            user_present: None,
+            iso_currency_code: None,
+        }
+    }
+
+    fn transfer_request(
+        access_token: &AccessToken,
+        authorization_id: &str,
+        idempotency_key: Option<&str>,
+    ) -> TransferCreateRequest {
+        TransferCreateRequest {
+            access_token: access_token.clone(),
+            account_id: "checking-0000".to_string(),
+            authorization_id: authorization_id.to_string(),
+            amount: None,
+            description: "payment".to_string(),
+            metadata: None,
+            iso_currency_code: None,
+            idempotency_key: idempotency_key.map(str::to_string),
+        }
+    }
+
+    /// A pending transfer of 50.00 out of the default checking account
+    async fn pending_transfer(plaid: &FakePlaid, access_token: &AccessToken) -> Transfer {
+        let authorization = plaid
+            .transfer_authorization_create(authorization_request(access_token, "50.00", None))
+            .await
+            .unwrap()
+            .authorization;
+        plaid
+            .transfer_create(transfer_request(access_token, &authorization.id, None))
+            .await
+            .unwrap()
+            .transfer
+    }
+
+    async fn simulate(
+        plaid: &FakePlaid,
+        transfer_id: &str,
+        event_type: TransferEventType,
+    ) -> PlaidResult<SandboxTransferSimulateResponse> {
+        plaid
+            .sandbox_transfer_simulate(SandboxTransferSimulateRequest {
+                transfer_id: transfer_id.to_string(),
+                event_type,
+                failure_reason: None,
+                test_clock_id: None,
+            })
+            .await
+    }
+
+    async fn event_types(plaid: &FakePlaid) -> Vec<TransferEventType> {
+        plaid
+            .transfer_event_sync(TransferEventSyncRequest {
+                after_id: 0,
+                count: None,
+            })
+            .await
+            .unwrap()
+            .transfer_events
+            .into_iter()
+            .map(|event| event.event_type)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn simulated_transfers_follow_the_plaid_lifecycle() {
+        let plaid = FakePlaid::new();
+        let access_token = plaid.add_item(FakeItem::default());
+        let transfer = pending_transfer(&plaid, &access_token).await;
+
+        assert_eq!(
+            error_code(simulate(&plaid, &transfer.id, TransferEventType::Settled).await),
+            "SANDBOX_TRANSFER_EVENT_TRANSITION_INVALID"
+        );
+        for event_type in [
+            TransferEventType::Posted,
+            TransferEventType::Settled,
+            TransferEventType::FundsAvailable,
+        ] {
+            simulate(&plaid, &transfer.id, event_type).await.unwrap();
+        }
+        assert_eq!(
+            error_code(simulate(&plaid, &transfer.id, TransferEventType::Posted).await),
+            "SANDBOX_TRANSFER_EVENT_TRANSITION_INVALID"
+        );
+        simulate(&plaid, &transfer.id, TransferEventType::Returned)
+            .await
+            .unwrap();
+
+        let transfer = plaid.transfer(&transfer.id).unwrap();
+        assert_eq!(transfer.status, TransferStatus::Returned);
+        assert!(!transfer.cancellable);
+        assert_eq!(
+            event_types(&plaid).await,
+            vec![
+                TransferEventType::Pending,
+                TransferEventType::Posted,
+                TransferEventType::Settled,
+                TransferEventType::FundsAvailable,
+                TransferEventType::Returned,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn only_pending_transfers_fail_or_cancel() {
+        let plaid = FakePlaid::new();
+        let access_token = plaid.add_item(FakeItem::default());
+
+        let failed = pending_transfer(&plaid, &access_token).await;
+        simulate(&plaid, &failed.id, TransferEventType::Failed)
+            .await
+            .unwrap();
+        assert_eq!(
+            error_code(simulate(&plaid, &failed.id, TransferEventType::Returned).await),
+            "SANDBOX_TRANSFER_EVENT_TRANSITION_INVALID"
+        );
+
+        let posted = pending_transfer(&plaid, &access_token).await;
+        simulate(&plaid, &posted.id, TransferEventType::Posted)
+            .await
+            .unwrap();
+        let cancel = plaid
+            .transfer_cancel(TransferCancelRequest {
+                transfer_id: posted.id.clone(),
+            })
+            .await;
+        assert_eq!(error_code(cancel), "TRANSFER_NOT_CANCELLABLE");
+
+        let cancelled = pending_transfer(&plaid, &access_token).await;
+        plaid
+            .transfer_cancel(TransferCancelRequest {
+                transfer_id: cancelled.id.clone(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            plaid.transfer(&cancelled.id).unwrap().status,
+            TransferStatus::Cancelled
+        );
+    }
+
+    #[tokio::test]
+    async fn debits_above_the_balance_are_declined_for_nsf() {
+        let plaid = FakePlaid::new();
+        let access_token = plaid.add_item(FakeItem::default());
+
+        let authorization = plaid
+            .transfer_authorization_create(authorization_request(&access_token, "110.01", None))
+            .await
+            .unwrap()
+            .authorization;
+        assert_eq!(
+            authorization.decision,
+            TransferAuthorizationDecision::Declined
+        );
+        assert_eq!(
+            authorization.decision_rationale.unwrap().code,
+            TransferAuthorizationDecisionRationaleCode::Nsf
+        );
+
+        let create = plaid
+            .transfer_create(transfer_request(&access_token, &authorization.id, None))
+            .await;
+        assert_eq!(error_code(create), "INVALID_FIELD");
+
+        let authorization = plaid
+            .transfer_authorization_create(authorization_request(&access_token, "110.00", None))
+            .await
+            .unwrap()
+            .authorization;
+        assert_eq!(
+            authorization.decision,
+            TransferAuthorizationDecision::Approved
+        );
+    }
+
+    #[tokio::test]
+    async fn authorizations_are_used_once() {
+        let plaid = FakePlaid::new();
+        let access_token = plaid.add_item(FakeItem::default());
+        let transfer = pending_transfer(&plaid, &access_token).await;
+
+        let reuse = plaid
+            .transfer_create(transfer_request(
+                &access_token,
+                &transfer.authorization_id,
+                None,
+            ))
+            .await;
+        assert_eq!(error_code(reuse), "INVALID_FIELD");
+        assert_eq!(
+            plaid
+                .transfer_list(TransferListRequest::default())
+                .await
+                .unwrap()
+                .transfers,
+            vec![transfer]
+        );
+    }
+
+    #[tokio::test]
+    async fn idempotency_keys_replay_the_first_result() {
+        let plaid = FakePlaid::new();
+        let access_token = plaid.add_item(FakeItem::default());
+
+        let request = authorization_request(&access_token, "50.00", Some("authorize-1"));
+        let first = plaid
+            .transfer_authorization_create(request.clone())
+            .await
+            .unwrap();
+        let replayed = plaid.transfer_authorization_create(request).await.unwrap();
+        assert_eq!(replayed.authorization, first.authorization);
+
+        let request = transfer_request(&access_token, &first.authorization.id, Some("create-1"));
+        let first = plaid.transfer_create(request.clone()).await.unwrap();
+        let replayed = plaid.transfer_create(request).await.unwrap();
+        assert_eq!(replayed.transfer, first.transfer);
+
+        let transfers = plaid
+            .transfer_list(TransferListRequest::default())
+            .await
+            .unwrap()
+            .transfers;
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(event_types(&plaid).await, vec![TransferEventType::Pending]);
+    }
+
+    #[tokio::test]
+    async fn queued_failures_are_returned_in_order_per_endpoint() {
+        let plaid = FakePlaid::new();
+        let access_token = plaid.add_item(FakeItem::default());
+        plaid.fail_next::<AccountsBalanceGetRequest>(plaid_error(
+            PlaidErrorType::RateLimitExceeded,
+            "RATE_LIMIT",
+            "rate limit exceeded",
+        ));
+        plaid.fail_next::<AccountsBalanceGetRequest>(plaid_error(
+            PlaidErrorType::ApiError,
+            "INTERNAL_SERVER_ERROR",
+            "an unexpected error occurred",
+        ));
+        let request = AccountsBalanceGetRequest {
+            access_token: access_token.clone(),
+            ..Default::default()
+        };
+
+        plaid
+            .item_get(ItemGetRequest {
+                access_token: access_token.clone(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            error_code(plaid.accounts_balance_get(request.clone()).await),
+            "RATE_LIMIT"
+        );
+        assert_eq!(
+            error_code(plaid.accounts_balance_get(request.clone()).await),
+            "INTERNAL_SERVER_ERROR"
+        );
+        plaid.accounts_balance_get(request).await.unwrap();
+
+        assert_eq!(
+            plaid.calls(),
+            vec![
+                ItemGetRequest::PATH,
+                AccountsBalanceGetRequest::PATH,
+                AccountsBalanceGetRequest::PATH,
+                AccountsBalanceGetRequest::PATH,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn transactions_sync_pages_by_cursor() {
+        let plaid = FakePlaid::new();
+        let item = ["tx-1", "tx-2", "tx-3"]
+            .into_iter()
+            .fold(FakeItem::default(), |item, id| {
+                item.with_transaction(transaction(
+                    id,
+                    "checking-0000",
+                    Amount::new(Decimal::new(1000, 2)),
+                ))
+            });
+        let access_token = plaid.add_item(item);
+        let request = |cursor: Option<&str>| TransactionsSyncRequest {
+            access_token: access_token.clone(),
+            cursor: cursor.map(str::to_string),
+            count: Some(2),
+            options: None,
+        };
+
+        let first = plaid.transactions_sync(request(Some(""))).await.unwrap();
+        assert_eq!(first.added.len(), 2);
+        assert!(first.has_more);
+
+        let second = plaid
+            .transactions_sync(request(Some(&first.next_cursor)))
+            .await
+            .unwrap();
+        assert_eq!(second.added[0].transaction_id, "tx-3");
+        assert!(!second.has_more);
+
+        let caught_up = plaid
+            .transactions_sync(request(Some(&second.next_cursor)))
+            .await
+            .unwrap();
+        assert!(caught_up.added.is_empty());
+        assert_eq!(caught_up.next_cursor, second.next_cursor);
+
+        let invalid = plaid.transactions_sync(request(Some("not-a-cursor"))).await;
+        assert_eq!(error_code(invalid), "INVALID_FIELD");
+    }
+
+    #[tokio::test]
+    async fn invalidated_access_tokens_stop_working() {
+        let plaid = FakePlaid::new();
+        let access_token = plaid.add_item(FakeItem::default());
+
+        let new_access_token = plaid
+            .item_access_token_invalidate(ItemAccessTokenInvalidateRequest {
+                access_token: access_token.clone(),
+            })
+            .await
+            .unwrap()
+            .new_access_token;
+
+        assert!(plaid.item(&access_token).is_none());
+        assert!(plaid.item(&new_access_token).is_some());
+        let invalidate = plaid
+            .item_access_token_invalidate(ItemAccessTokenInvalidateRequest { access_token })
+            .await;
+        assert_eq!(error_code(invalidate), "INVALID_ACCESS_TOKEN");
+    }
+}
@@ -1,74 +1,365 @@
 use crate::{
+    api::transfer::TransferEventStream,
     error::PlaidError,
     model::{
         accounts::{AccountsBalanceGetRequest, AccountsGetResponse},
-        error::PlaidErrorResponse,
-        items::{ItemPublicTokenExchangeRequest, ItemPublicTokenExchangeResponse},
+        auth::{AuthGetRequest, AuthGetResponse},
+        error::{PlaidErrorCode, TransactionsErrorCode},
+        identity::{
+            IdentityGetRequest, IdentityGetResponse, IdentityMatchRequest, IdentityMatchResponse,
+        },
+        institutions::{
+            InstitutionsGetByIdRequest, InstitutionsGetByIdResponse, InstitutionsGetRequest,
+            InstitutionsGetResponse, InstitutionsSearchRequest, InstitutionsSearchResponse,
+        },
+        items::{
+            ItemAccessTokenInvalidateRequest, ItemAccessTokenInvalidateResponse, ItemGetRequest,
+            ItemGetResponse, ItemPublicTokenExchangeRequest, ItemPublicTokenExchangeResponse,
+            ItemRemoveRequest, ItemRemoveResponse, ItemWebhookUpdateRequest,
+            ItemWebhookUpdateResponse,
+        },
         link::{LinkTokenCreateRequest, LinkTokenCreateResponse},
+        sandbox::{
+            SandboxItemFireWebhookRequest, SandboxItemFireWebhookResponse,
+            SandboxItemResetLoginRequest, SandboxItemResetLoginResponse,
+            SandboxPublicTokenCreateRequest, SandboxPublicTokenCreateResponse,
+            SandboxTransferSimulateRequest, SandboxTransferSimulateResponse,
+        },
+        transactions::{TransactionsSyncRequest, TransactionsSyncResponse, TransactionsSyncUpdate},
+        transfer::{
+            TransferAuthorizationCreateRequest, TransferAuthorizationCreateResponse,
+            TransferCancelRequest, TransferCancelResponse, TransferCreateRequest,
+            TransferCreateResponse, TransferEventListRequest, TransferEventListResponse,
+            TransferEventSyncRequest, TransferEventSyncResponse, TransferGetRequest,
+            TransferGetResponse, TransferListRequest, TransferListResponse,
+        },
     },
-    Either, PlaidResult,
+    retry::RetryPolicy,
+    PlaidResult,
 };
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
-#[async_trait]
-pub trait PlaidApi<Req: Serialize + Send + 'static + Sync, Resp: Debug + for<'a> Deserialize<'a>> {
-    async fn call(
-        &self,
-        client: &reqwest::Client,
-        req: Req,
-        url: &str,
-    ) -> PlaidResult<Either<Resp, PlaidErrorResponse>> {
-        let res = client
-            .post(url)
-            .json(&req)
-            .send()
-            .await
-            .map_err(PlaidError::Reqwest)?;
-
-        let status = res.status();
-        println!("status = {status:#?}");
-        if status.is_success() {
-            let succ_res = res.json::<Resp>().await.map_err(PlaidError::Reqwest)?;
-            println!("succ_res = {succ_res:#?}");
-            Ok(Either::Left(succ_res))
-        } else {
-            let failure_res = res
-                .json::<PlaidErrorResponse>()
-                .await
-                .map_err(PlaidError::Reqwest)?;
-            println!("failure = {failure_res:#?}");
-            Ok(Either::Right(failure_res))
-        }
+/// A request to a single Plaid endpoint. Implementing this for a request type
+/// is all it takes to send it with [`PlaidClient::execute`](crate::PlaidClient::execute).
+pub trait Endpoint: Serialize + Send + Sync + 'static {
+    type Response: Debug + Send + for<'a> Deserialize<'a>;
+
+    /// Path below the environment's base URL, e.g. `/item/get`
+    const PATH: &'static str;
+
+    /// Refused with `PlaidError::SandboxOnly` outside the sandbox (or a custom host)
+    const SANDBOX_ONLY: bool = false;
+
+    /// The retry policy for this request, given the client's
+    fn retry_policy(&self, client_policy: &RetryPolicy) -> RetryPolicy {
+        client_policy.clone()
     }
 }
 
 #[async_trait]
-pub trait LinkApi: PlaidApi<LinkTokenCreateRequest, LinkTokenCreateResponse> {
+pub trait LinkApi: Send + Sync {
     async fn link_token_create(
         &self,
         req: LinkTokenCreateRequest,
-    ) -> PlaidResult<Either<LinkTokenCreateResponse, PlaidErrorResponse>>;
+    ) -> PlaidResult<LinkTokenCreateResponse>;
 }
 
 #[async_trait]
-pub trait ItemsApi:
-    PlaidApi<ItemPublicTokenExchangeRequest, ItemPublicTokenExchangeResponse>
-{
+pub trait ItemsApi: Send + Sync {
     async fn item_public_token_exchange(
         &self,
         req: ItemPublicTokenExchangeRequest,
-    ) -> PlaidResult<Either<ItemPublicTokenExchangeResponse, PlaidErrorResponse>>;
+    ) -> PlaidResult<ItemPublicTokenExchangeResponse>;
+
+    async fn item_get(&self, req: ItemGetRequest) -> PlaidResult<ItemGetResponse>;
+
+    async fn item_remove(&self, req: ItemRemoveRequest) -> PlaidResult<ItemRemoveResponse>;
+
+    async fn item_webhook_update(
+        &self,
+        req: ItemWebhookUpdateRequest,
+    ) -> PlaidResult<ItemWebhookUpdateResponse>;
+
+    async fn item_access_token_invalidate(
+        &self,
+        req: ItemAccessTokenInvalidateRequest,
+    ) -> PlaidResult<ItemAccessTokenInvalidateResponse>;
 }
 
 #[async_trait]
-pub trait AccountsApi: PlaidApi<AccountsBalanceGetRequest, AccountsGetResponse> {
+pub trait AccountsApi: Send + Sync {
     async fn accounts_balance_get(
         &self,
         req: AccountsBalanceGetRequest,
-    ) -> PlaidResult<Either<AccountsGetResponse, PlaidErrorResponse>>;
+    ) -> PlaidResult<AccountsGetResponse>;
+}
+
+#[async_trait]
+pub trait AuthApi: Send + Sync {
+    async fn auth_get(&self, req: AuthGetRequest) -> PlaidResult<AuthGetResponse>;
+}
+
+#[async_trait]
+pub trait IdentityApi: Send + Sync {
+    async fn identity_get(&self, req: IdentityGetRequest) -> PlaidResult<IdentityGetResponse>;
+
+    async fn identity_match(&self, req: IdentityMatchRequest)
+        -> PlaidResult<IdentityMatchResponse>;
+}
+
+#[async_trait]
+pub trait InstitutionsApi: Send + Sync {
+    async fn institutions_get(
+        &self,
+        req: InstitutionsGetRequest,
+    ) -> PlaidResult<InstitutionsGetResponse>;
+
+    async fn institutions_search(
+        &self,
+        req: InstitutionsSearchRequest,
+    ) -> PlaidResult<InstitutionsSearchResponse>;
+
+    async fn institutions_get_by_id(
+        &self,
+        req: InstitutionsGetByIdRequest,
+    ) -> PlaidResult<InstitutionsGetByIdResponse>;
+}
+
+/// How often [`TransactionsApi::transactions_sync_all`] starts over when the
+/// item's data changes while it pages
+pub const MAX_SYNC_RESTARTS: u32 = 3;
+
+#[async_trait]
+pub trait TransactionsApi: Send + Sync {
+    async fn transactions_sync(
+        &self,
+        req: TransactionsSyncRequest,
+    ) -> PlaidResult<TransactionsSyncResponse>;
+
+    /// Follows `has_more` from `req.cursor` until the item is caught up.
+    ///
+    /// If Plaid reports that the data changed mid-pagination, the whole sync
+    /// is restarted from the original cursor, as the Plaid docs require, up
+    /// to [`MAX_SYNC_RESTARTS`] times before the error is returned.
+    async fn transactions_sync_all(
+        &self,
+        req: TransactionsSyncRequest,
+    ) -> PlaidResult<TransactionsSyncUpdate> {
+        let start_cursor = req.cursor.clone();
+        let mut req = req;
+        let mut update = TransactionsSyncUpdate::default();
+        let mut restarts = 0;
+
+        loop {
+            match self.transactions_sync(req.clone()).await {
+                Ok(page) => {
+                    update.added.extend(page.added);
+                    update.modified.extend(page.modified);
+                    update.removed.extend(page.removed);
+                    update.next_cursor = page.next_cursor.clone();
+
+                    if !page.has_more {
+                        return Ok(update);
+                    }
+                    req.cursor = Some(page.next_cursor);
+                }
+                Err(PlaidError::Api(failure))
+                    if restarts < MAX_SYNC_RESTARTS
+                        && failure.error_code
+                            == PlaidErrorCode::TransactionsError(
+                                TransactionsErrorCode::TransactionsSyncMutationDuringPagination,
+                            ) =>
+                {
+                    restarts += 1;
+                    req.cursor = start_cursor.clone();
+                    update = TransactionsSyncUpdate::default();
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+#[async_trait]
+pub trait TransferApi: Send + Sync {
+    async fn transfer_authorization_create(
+        &self,
+        req: TransferAuthorizationCreateRequest,
+    ) -> PlaidResult<TransferAuthorizationCreateResponse>;
+
+    async fn transfer_create(
+        &self,
+        req: TransferCreateRequest,
+    ) -> PlaidResult<TransferCreateResponse>;
+
+    async fn transfer_get(&self, req: TransferGetRequest) -> PlaidResult<TransferGetResponse>;
+
+    async fn transfer_list(&self, req: TransferListRequest) -> PlaidResult<TransferListResponse>;
+
+    async fn transfer_cancel(
+        &self,
+        req: TransferCancelRequest,
+    ) -> PlaidResult<TransferCancelResponse>;
+
+    async fn transfer_event_list(
+        &self,
+        req: TransferEventListRequest,
+    ) -> PlaidResult<TransferEventListResponse>;
+
+    async fn transfer_event_sync(
+        &self,
+        req: TransferEventSyncRequest,
+    ) -> PlaidResult<TransferEventSyncResponse>;
+
+    /// Walks `/transfer/event/sync` starting from `req.after_id`, one event at a time
+    fn transfer_event_stream(&self, req: TransferEventSyncRequest) -> TransferEventStream<'_, Self>
+    where
+        Self: Sized,
+    {
+        TransferEventStream::new(self, req)
+    }
 }
 
+/// Testing helpers that only exist on `PlaidEnv::Sandbox` (or a `Custom` stand-in).
+/// Calls made against production fail with `PlaidError::SandboxOnly` without
+/// being sent.
 #[async_trait]
-pub trait TransferApi {}
+pub trait SandboxApi: Send + Sync {
+    async fn sandbox_public_token_create(
+        &self,
+        req: SandboxPublicTokenCreateRequest,
+    ) -> PlaidResult<SandboxPublicTokenCreateResponse>;
+
+    async fn sandbox_item_reset_login(
+        &self,
+        req: SandboxItemResetLoginRequest,
+    ) -> PlaidResult<SandboxItemResetLoginResponse>;
+
+    async fn sandbox_item_fire_webhook(
+        &self,
+        req: SandboxItemFireWebhookRequest,
+    ) -> PlaidResult<SandboxItemFireWebhookResponse>;
+
+    async fn sandbox_transfer_simulate(
+        &self,
+        req: SandboxTransferSimulateRequest,
+    ) -> PlaidResult<SandboxTransferSimulateResponse>;
+}
+
+#[cfg(all(test, feature = "test-util"))]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{
+            common::Amount,
+            error::{PlaidErrorResponse, PlaidErrorType},
+            secret::AccessToken,
+        },
+        testing::{plaid_error, transaction, FakeItem, FakePlaid},
+    };
+    use rust_decimal::Decimal;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    fn mutation_during_pagination() -> PlaidErrorResponse {
+        plaid_error(
+            PlaidErrorType::TransactionsError,
+            "TRANSACTIONS_SYNC_MUTATION_DURING_PAGINATION",
+            "underlying transaction data changed since the last page was fetched",
+        )
+    }
+
+    fn with_transactions(ids: &[&str]) -> FakeItem {
+        ids.iter().fold(FakeItem::default(), |item, id| {
+            item.with_transaction(transaction(
+                id,
+                "checking-0000",
+                Amount::new(Decimal::new(1000, 2)),
+            ))
+        })
+    }
+
+    fn sync_request(access_token: &AccessToken) -> TransactionsSyncRequest {
+        TransactionsSyncRequest {
+            access_token: access_token.clone(),
+            count: Some(2),
+            ..Default::default()
+        }
+    }
+
+    /// Changes the item's transactions after handing out the first page,
+    /// like Plaid does when new data arrives mid-pagination
+    struct MutatesAfterFirstPage {
+        plaid: FakePlaid,
+        mutated: AtomicBool,
+    }
+
+    #[async_trait]
+    impl TransactionsApi for MutatesAfterFirstPage {
+        async fn transactions_sync(
+            &self,
+            req: TransactionsSyncRequest,
+        ) -> PlaidResult<TransactionsSyncResponse> {
+            let access_token = req.access_token.clone();
+            let page = self.plaid.transactions_sync(req).await?;
+            if !self.mutated.swap(true, Ordering::SeqCst) {
+                self.plaid
+                    .fail_next::<TransactionsSyncRequest>(mutation_during_pagination());
+                self.plaid.update_item(&access_token, |item| {
+                    item.transactions =
+                        with_transactions(&["tx-1", "tx-2", "tx-3", "tx-4"]).transactions;
+                });
+            }
+            Ok(page)
+        }
+    }
+
+    #[tokio::test]
+    async fn restart_discards_the_pages_fetched_before_it() {
+        let plaid = FakePlaid::new();
+        let access_token = plaid.add_item(with_transactions(&["tx-1", "tx-2", "tx-3"]));
+        let client = MutatesAfterFirstPage {
+            plaid,
+            mutated: Default::default(),
+        };
+
+        let update = client
+            .transactions_sync_all(sync_request(&access_token))
+            .await
+            .unwrap();
+
+        let ids: Vec<&str> = update
+            .added
+            .iter()
+            .map(|transaction| transaction.transaction_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["tx-1", "tx-2", "tx-3", "tx-4"]);
+        assert_eq!(update.next_cursor, "4");
+        // first page, the failed second page, then two pages from the start
+        assert_eq!(client.plaid.calls().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn mutation_error_is_returned_after_max_restarts() {
+        let plaid = FakePlaid::new();
+        let access_token = plaid.add_item(with_transactions(&["tx-1"]));
+        for _ in 0..=MAX_SYNC_RESTARTS {
+            plaid.fail_next::<TransactionsSyncRequest>(mutation_during_pagination());
+        }
+
+        let err = plaid
+            .transactions_sync_all(sync_request(&access_token))
+            .await
+            .unwrap_err();
+
+        match err {
+            PlaidError::Api(failure) => assert_eq!(
+                failure.error_code.as_str(),
+                "TRANSACTIONS_SYNC_MUTATION_DURING_PAGINATION"
+            ),
+            err => panic!("expected the mutation error, got {err:?}"),
+        }
+        assert_eq!(plaid.calls().len(), MAX_SYNC_RESTARTS as usize + 1);
+    }
+}
@@ -0,0 +1,148 @@
+//! Sends a single request to Plaid and logs its outcome without leaking
+//! credentials or account holder data
+
+use std::{fmt::Debug, time::Instant};
+
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::{debug, trace, warn, Level, Span};
+
+use crate::{error::PlaidError, model::error::PlaidErrorResponse, PlaidResult};
+
+/// JSON keys whose values must never reach the logs: credentials, account
+/// numbers and the account holder's identity
+const REDACTED_KEYS: &[&str] = &[
+    "access_token",
+    "account",
+    "addresses",
+    "bic",
+    "branch",
+    "data",
+    "email_address",
+    "emails",
+    "iban",
+    "institution",
+    "legal_name",
+    "link_token",
+    "names",
+    "new_access_token",
+    "phone_number",
+    "phone_numbers",
+    "public_token",
+    "routing",
+    "secret",
+    "sort_code",
+    "wire_routing",
+];
+
+fn redact(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if REDACTED_KEYS.contains(&key.as_str()) {
+                    *value = Value::String("[REDACTED]".into());
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+/// Sends a single attempt, recording its outcome on the current span
+pub(crate) async fn send<Req: Serialize, Resp: Debug + for<'a> Deserialize<'a>>(
+    client: &reqwest::Client,
+    headers: &HeaderMap,
+    req: &Req,
+    url: &str,
+) -> PlaidResult<Resp> {
+    let span = Span::current();
+    let started = Instant::now();
+
+    let res = client
+        .post(url)
+        .headers(headers.clone())
+        .json(req)
+        .send()
+        .await
+        .map_err(PlaidError::Reqwest)?;
+
+    let status = res.status();
+    let body = res.text().await.map_err(PlaidError::Reqwest)?;
+    span.record("status", status.as_u16());
+    span.record("latency_ms", started.elapsed().as_millis() as u64);
+
+    let value = match serde_json::from_str::<Value>(&body) {
+        Ok(value) => value,
+        Err(_) if !status.is_success() => {
+            warn!("non-JSON error response from Plaid");
+            return Err(PlaidError::UnexpectedStatus(status.as_u16()));
+        }
+        Err(err) => return Err(PlaidError::Serde(err)),
+    };
+    if let Some(request_id) = value.get("request_id").and_then(Value::as_str) {
+        span.record("request_id", request_id);
+    }
+    if tracing::enabled!(Level::TRACE) {
+        let mut redacted = value.clone();
+        redact(&mut redacted);
+        trace!(body = %redacted, "Plaid response body");
+    }
+
+    if status.is_success() {
+        let succ_res = serde_json::from_value::<Resp>(value).map_err(PlaidError::Serde)?;
+        debug!("Plaid request succeeded");
+        Ok(succ_res)
+    } else {
+        let failure_res =
+            serde_json::from_value::<PlaidErrorResponse>(value).map_err(PlaidError::Serde)?;
+        span.record("error_code", failure_res.error_code.as_str());
+        warn!("Plaid request failed");
+        Err(PlaidError::Api(Box::new(failure_res)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn redaction_masks_nested_secrets_and_pii_only() {
+        let mut body = json!({
+            "access_token": "access-sandbox-1234",
+            "secret": "plaid-secret",
+            "request_id": "abc123",
+            "accounts": [{
+                "account_id": "checking-0000",
+                "owners": [{
+                    "names": ["Alberta Charleson"],
+                    "emails": [{ "data": "alberta@example.com", "primary": true }],
+                }],
+            }],
+            "numbers": { "ach": [{ "account": "1111222233330000", "routing": "011401533" }] },
+        });
+
+        redact(&mut body);
+
+        assert_eq!(
+            body,
+            json!({
+                "access_token": "[REDACTED]",
+                "secret": "[REDACTED]",
+                "request_id": "abc123",
+                "accounts": [{
+                    "account_id": "checking-0000",
+                    "owners": [{
+                        "names": "[REDACTED]",
+                        "emails": "[REDACTED]",
+                    }],
+                }],
+                "numbers": { "ach": [{ "account": "[REDACTED]", "routing": "[REDACTED]" }] },
+            })
+        );
+    }
+}
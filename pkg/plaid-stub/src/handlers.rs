@@ -0,0 +1,283 @@
+use actix_web::{http::StatusCode, post, web, HttpResponse};
+use log::info;
+use plaid_sdk::{
+    error::PlaidError,
+    model::{
+        accounts::{AccountBalanceGetRequestOptions, AccountsBalanceGetRequest},
+        common::Product,
+        error::PlaidErrorType,
+        items::ItemPublicTokenExchangeRequest,
+        link::{CountryCode, LinkTokenCreateRequest, User},
+        sandbox::{SandboxPublicTokenCreateRequest, SandboxPublicTokenCreateRequestOptions},
+        secret::{AccessToken, PublicToken},
+    },
+    testing::{plaid_error, FakePlaid},
+    traits::{AccountsApi, ItemsApi, LinkApi, SandboxApi},
+    PlaidResult,
+};
+use serde::{Deserialize, Serialize};
+
+/// Answers like Plaid: the response body on success, otherwise the error
+/// object with its HTTP status
+fn respond<T: Serialize>(result: PlaidResult<T>) -> HttpResponse {
+    match result {
+        Ok(body) => HttpResponse::Ok().json(body),
+        Err(PlaidError::Api(failure)) => {
+            let status = failure
+                .status
+                .and_then(|status| u16::try_from(status).ok())
+                .and_then(|status| StatusCode::from_u16(status).ok())
+                .unwrap_or(StatusCode::BAD_REQUEST);
+            HttpResponse::build(status).json(failure)
+        }
+        Err(err) => HttpResponse::BadRequest().json(plaid_error(
+            PlaidErrorType::InvalidRequest,
+            "INVALID_FIELD",
+            &err.to_string(),
+        )),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct LinkTokenCreateUser {
+    client_user_id: String,
+}
+
+/// The part of `/link/token/create` the stub looks at. Like the other inputs
+/// below it is declared here, as the request types of the SDK are only meant
+/// to be sent.
+#[derive(Debug, Deserialize)]
+pub(crate) struct LinkTokenCreateInput {
+    client_name: String,
+    user: LinkTokenCreateUser,
+    country_codes: Vec<CountryCode>,
+    products: Vec<Product>,
+    redirect_uri: Option<String>,
+    android_package_name: Option<String>,
+}
+
+#[post("/link/token/create")]
+pub(crate) async fn link_token_create(
+    plaid: web::Data<FakePlaid>,
+    payload: web::Json<LinkTokenCreateInput>,
+) -> HttpResponse {
+    let payload = payload.into_inner();
+    info!("Creating a link token for {}", payload.user.client_user_id);
+
+    let mut builder = LinkTokenCreateRequest::builder(
+        &payload.client_name,
+        User {
+            client_user_id: payload.user.client_user_id,
+            ..Default::default()
+        },
+        payload.country_codes,
+        payload.products,
+    );
+    if let Some(redirect_uri) = &payload.redirect_uri {
+        builder = builder.redirect_uri(redirect_uri);
+    }
+    if let Some(android_package_name) = &payload.android_package_name {
+        builder = builder.android_package_name(android_package_name);
+    }
+
+    let result = match builder.build() {
+        Ok(req) => plaid.link_token_create(req).await,
+        Err(err) => Err(err),
+    };
+    respond(result)
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ItemPublicTokenExchangeInput {
+    public_token: PublicToken,
+}
+
+#[post("/item/public_token/exchange")]
+pub(crate) async fn item_public_token_exchange(
+    plaid: web::Data<FakePlaid>,
+    payload: web::Json<ItemPublicTokenExchangeInput>,
+) -> HttpResponse {
+    info!("Exchanging a public token");
+    let req = ItemPublicTokenExchangeRequest {
+        public_token: payload.into_inner().public_token,
+    };
+    respond(plaid.item_public_token_exchange(req).await)
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct AccountsBalanceGetInput {
+    access_token: AccessToken,
+    options: Option<AccountBalanceGetRequestOptions>,
+}
+
+#[post("/accounts/balance/get")]
+pub(crate) async fn accounts_balance_get(
+    plaid: web::Data<FakePlaid>,
+    payload: web::Json<AccountsBalanceGetInput>,
+) -> HttpResponse {
+    info!("Getting balances");
+    let payload = payload.into_inner();
+    let req = AccountsBalanceGetRequest {
+        access_token: payload.access_token,
+        options: payload.options,
+    };
+    respond(plaid.accounts_balance_get(req).await)
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct SandboxPublicTokenCreateInput {
+    institution_id: String,
+    initial_products: Vec<Product>,
+    options: Option<SandboxPublicTokenCreateRequestOptions>,
+}
+
+#[post("/sandbox/public_token/create")]
+pub(crate) async fn sandbox_public_token_create(
+    plaid: web::Data<FakePlaid>,
+    payload: web::Json<SandboxPublicTokenCreateInput>,
+) -> HttpResponse {
+    let payload = payload.into_inner();
+    info!(
+        "Creating a sandbox public token at {}",
+        payload.institution_id
+    );
+    let req = SandboxPublicTokenCreateRequest {
+        institution_id: payload.institution_id,
+        initial_products: payload.initial_products,
+        options: payload.options,
+    };
+    respond(plaid.sandbox_public_token_create(req).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes;
+    use actix_web::{test, App};
+    use plaid_sdk::testing::FakeItem;
+    use serde_json::{json, Value};
+
+    /// Posts `body` to `uri` and returns the status and the JSON response
+    macro_rules! post {
+        ($app:expr, $uri:expr, $body:expr) => {{
+            let req = test::TestRequest::post()
+                .uri($uri)
+                .set_json($body)
+                .to_request();
+            let res = test::call_service(&$app, req).await;
+            let status = res.status();
+            let body: Value = test::read_body_json(res).await;
+            (status, body)
+        }};
+    }
+
+    macro_rules! stub {
+        ($plaid:expr) => {
+            test::init_service(
+                App::new()
+                    .app_data(web::Data::new($plaid))
+                    .configure(routes),
+            )
+            .await
+        };
+    }
+
+    #[actix_web::test]
+    async fn link_token_create_returns_a_link_token() {
+        let app = stub!(FakePlaid::new());
+        let input = json!({
+            "client_name": "Tiny Quickstart",
+            "user": { "client_user_id": "user-1" },
+            "country_codes": ["US"],
+            "products": ["auth"],
+        });
+
+        let (status, body) = post!(app, "/link/token/create", input.clone());
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["link_token"]
+            .as_str()
+            .unwrap()
+            .starts_with("link-sandbox-"));
+        assert!(body["expiration"].is_string());
+        assert!(body["request_id"].is_string());
+
+        let mut input = input;
+        input["products"] = json!([]);
+        let (status, body) = post!(app, "/link/token/create", input);
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error_type"], "INVALID_REQUEST");
+        assert_eq!(body["error_code"], "INVALID_FIELD");
+    }
+
+    #[actix_web::test]
+    async fn item_public_token_exchange_links_the_item_once() {
+        let plaid = FakePlaid::new();
+        let public_token = plaid.create_public_token(FakeItem::default());
+        let app = stub!(plaid);
+        let input = json!({ "public_token": public_token.expose_secret() });
+
+        let (status, body) = post!(app, "/item/public_token/exchange", input.clone());
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["access_token"]
+            .as_str()
+            .unwrap()
+            .starts_with("access-sandbox-"));
+        assert!(body["item_id"].is_string());
+
+        let (status, body) = post!(app, "/item/public_token/exchange", input);
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error_type"], "INVALID_INPUT");
+        assert_eq!(body["error_code"], "INVALID_PUBLIC_TOKEN");
+    }
+
+    #[actix_web::test]
+    async fn accounts_balance_get_maps_plaid_errors_to_their_status() {
+        let plaid = FakePlaid::new();
+        let access_token = plaid.add_item(FakeItem::default());
+        plaid.fail_next::<AccountsBalanceGetRequest>(plaid_error(
+            PlaidErrorType::RateLimitExceeded,
+            "RATE_LIMIT",
+            "rate limit exceeded",
+        ));
+        plaid.fail_next::<AccountsBalanceGetRequest>(plaid_error(
+            PlaidErrorType::ApiError,
+            "INTERNAL_SERVER_ERROR",
+            "an unexpected error occurred",
+        ));
+        let app = stub!(plaid);
+        let input = json!({ "access_token": access_token.expose_secret() });
+
+        let (status, body) = post!(app, "/accounts/balance/get", input.clone());
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(body["error_code"], "RATE_LIMIT");
+
+        let (status, body) = post!(app, "/accounts/balance/get", input.clone());
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(body["error_code"], "INTERNAL_SERVER_ERROR");
+
+        let (status, body) = post!(app, "/accounts/balance/get", input);
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["accounts"][0]["account_id"], "checking-0000");
+        assert_eq!(body["accounts"][0]["balances"]["available"], 110.0);
+        assert_eq!(body["accounts"][0]["balances"]["iso_currency_code"], "USD");
+        assert_eq!(body["accounts"].as_array().unwrap().len(), 2);
+    }
+
+    #[actix_web::test]
+    async fn sandbox_public_token_create_rejects_malformed_bodies() {
+        let app = stub!(FakePlaid::new());
+
+        let input = json!({ "institution_id": "ins_109508", "initial_products": ["auth"] });
+        let (status, body) = post!(app, "/sandbox/public_token/create", input);
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["public_token"]
+            .as_str()
+            .unwrap()
+            .starts_with("public-sandbox-"));
+
+        let input = json!({ "initial_products": ["auth"] });
+        let (status, body) = post!(app, "/sandbox/public_token/create", input);
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error_code"], "INVALID_BODY");
+    }
+}
@@ -0,0 +1,46 @@
+//! Serves Plaid's REST shapes on localhost from an in-memory
+//! [`FakePlaid`], so the server and the app can run without reaching Plaid.
+//! Point a client at it with a custom `PlaidEnv`, e.g.
+//! `--plaid-base-url http://127.0.0.1:8081`.
+
+pub(crate) mod handlers;
+
+use actix_server::Server;
+use actix_web::{error::InternalError, web, App, HttpResponse, HttpServer};
+use handlers::{
+    accounts_balance_get, item_public_token_exchange, link_token_create,
+    sandbox_public_token_create,
+};
+use plaid_sdk::{
+    model::error::PlaidErrorType,
+    testing::{plaid_error, FakePlaid},
+};
+
+/// Serves `plaid` on `127.0.0.1:port`. Every request shares its state, so
+/// items seeded beforehand (e.g. with `FakePlaid::create_public_token`) can
+/// be reached over HTTP.
+pub fn create_server(plaid: FakePlaid, port: u16) -> std::io::Result<Server> {
+    let plaid = web::Data::new(plaid);
+
+    Ok(
+        HttpServer::new(move || App::new().app_data(plaid.clone()).configure(routes))
+            .bind(("127.0.0.1", port))?
+            .run(),
+    )
+}
+
+/// The Plaid routes, with malformed bodies answered like Plaid does
+pub(crate) fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.app_data(web::JsonConfig::default().error_handler(|err, _req| {
+        let body = plaid_error(
+            PlaidErrorType::InvalidRequest,
+            "INVALID_BODY",
+            &err.to_string(),
+        );
+        InternalError::from_response(err, HttpResponse::BadRequest().json(body)).into()
+    }))
+    .service(link_token_create)
+    .service(item_public_token_exchange)
+    .service(accounts_balance_get)
+    .service(sandbox_public_token_create);
+}
@@ -0,0 +1,21 @@
+use clap::Parser;
+use log::info;
+use plaid_sdk::testing::FakePlaid;
+use plaid_stub::create_server;
+
+#[derive(Debug, Parser)]
+#[command(version, about, long_about = None)]
+struct Config {
+    #[arg(env, long, default_value_t = 8081)]
+    plaid_stub_port: u16,
+}
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    env_logger::init();
+
+    let config = Config::parse();
+    let server = create_server(FakePlaid::new(), config.plaid_stub_port)?;
+    info!("Starting Plaid stub on port {}", config.plaid_stub_port);
+    Ok(server.await?)
+}
@@ -37,6 +37,17 @@ pub(crate) struct Config {
     #[arg(env, long, value_enum)]
     pub plaid_env: PlaidEnv,
 
+    /// Overrides the Plaid host, e.g. to point at a local stand-in server
+    #[arg(env, long)]
+    pub plaid_base_url: Option<String>,
+
+    #[arg(env, long, default_value = plaid_sdk::PLAID_VERSION)]
+    pub plaid_version: String,
+
+    /// Upper bound in seconds for a single Plaid request
+    #[arg(env, long, default_value_t = 30)]
+    pub plaid_timeout_secs: u64,
+
     #[arg(env, long)]
     pub plaid_redirect_uri: String,
 
@@ -2,6 +2,7 @@ use diesel::{
     associations::HasTable, Connection, ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl,
 };
 use log::debug;
+use plaid_sdk::model::secret::AccessToken;
 
 use crate::{
     models::{Client, NewClient},
@@ -34,7 +35,7 @@ pub(crate) async fn insert_client(client_user_id: &str, database_url: &str) -> e
 
     let mut conn = establish_conection(database_url);
     let new_client = NewClient {
-        client_user_id: client_user_id,
+        client_user_id,
         access_token: None,
     };
 
@@ -45,18 +46,15 @@ pub(crate) async fn insert_client(client_user_id: &str, database_url: &str) -> e
 
 pub(crate) async fn update_client(
     id: &str,
-    token: &str,
+    token: &AccessToken,
     database_url: &str,
 ) -> eyre::Result<usize> {
-    debug!(
-        "Updating db for client_user_id {} with access token {}",
-        id, token
-    );
+    debug!("Updating access token for client_user_id {}", id);
 
     let mut conn = establish_conection(database_url);
     Ok(
         diesel::update(clients::table.filter(clients::client_user_id.eq(id)))
-            .set(clients::access_token.eq(token.to_string()))
+            .set(clients::access_token.eq(token.expose_secret()))
             .execute(&mut conn)?,
     )
 }
@@ -8,6 +8,9 @@ pub enum Error {
 
     #[error(transparent)]
     GenericError(#[from] eyre::ErrReport),
+
+    #[error("Client {0} has not linked an item yet")]
+    NotLinked(String),
 }
 
 impl ResponseError for Error {
@@ -16,9 +19,12 @@ impl ResponseError for Error {
             Error::PlaidError(err) => match err {
                 PlaidError::InvalidDateOfBirth(_) => StatusCode::BAD_REQUEST,
                 PlaidError::InvalidPhoneNumber(_) => StatusCode::BAD_REQUEST,
+                PlaidError::InvalidRegion { .. } => StatusCode::BAD_REQUEST,
+                PlaidError::InvalidPostalCode { .. } => StatusCode::BAD_REQUEST,
                 _ => StatusCode::INTERNAL_SERVER_ERROR,
             },
             Error::GenericError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::NotLinked(_) => StatusCode::BAD_REQUEST,
         }
     }
 }
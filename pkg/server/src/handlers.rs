@@ -1,23 +1,35 @@
 use std::sync::Arc;
 
 use crate::{db, AppState};
-use actix_web::{body::BoxBody, http::header::ContentType, post, web, HttpResponse, Responder};
+use actix_web::{body::BoxBody, post, web, HttpResponse, Responder};
 use log::{debug, info};
 use plaid_sdk::{
+    error::PlaidError,
     model::{
         accounts::{AccountsBalanceGetRequest, AccountsGetResponse},
         common::Product,
         error::PlaidErrorResponse,
         items::ItemPublicTokenExchangeRequest,
         link::{CountryCode, Language, LinkTokenCreateRequest, User},
+        secret::PublicToken,
     },
-    traits::{AccountsApi, ItemsApi, LinkApi},
-    Either,
 };
 use serde::{Deserialize, Serialize};
 
 type HttpResult<T> = std::result::Result<T, crate::error::Error>;
 
+/// Plaid API failures are reported to the app in the response body, anything
+/// else becomes an HTTP error
+fn plaid_failure<T: From<PlaidErrorResponse>>(err: PlaidError) -> HttpResult<T> {
+    match err {
+        PlaidError::Api(failure) => {
+            info!("plaid error response = {:#?}", failure);
+            Ok((*failure).into())
+        }
+        err => Err(err.into()),
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateLinkTokenInput {
     client_user_id: String,
@@ -30,14 +42,20 @@ pub struct CreateLinkTokenOutput {
     failure: Option<PlaidErrorResponse>,
 }
 
+impl From<PlaidErrorResponse> for CreateLinkTokenOutput {
+    fn from(failure: PlaidErrorResponse) -> Self {
+        Self {
+            link_token: None,
+            failure: Some(failure),
+        }
+    }
+}
+
 impl Responder for CreateLinkTokenOutput {
     type Body = BoxBody;
 
     fn respond_to(self, _req: &actix_web::HttpRequest) -> HttpResponse<Self::Body> {
-        let body = serde_json::to_string(&self).unwrap();
-        HttpResponse::Ok()
-            .content_type(ContentType::json())
-            .body(body)
+        HttpResponse::Ok().json(self)
     }
 }
 
@@ -53,66 +71,51 @@ pub async fn create_link_token<'a>(
 
     let plaid_client = &state.plaid_client;
 
-    let payload = match payload.address.as_str() {
-        "localhost" => LinkTokenCreateRequest {
-            client_id: plaid_client.plaid_client_id.clone(),
-            secret: plaid_client.plaid_secret.clone(),
-            client_name: "Plaid Tiny QuickStart - expo RN".to_string(),
-            language: Language::English,
-            country_codes: vec![CountryCode::Us],
-            user: User {
-                client_user_id: id.clone(),
-                ..Default::default()
-            },
-            products: vec![Product::Auth],
-            redirect_uri: Some(plaid_client.plaid_redirect_uri.clone()),
-            ..Default::default()
-        },
-        _ => LinkTokenCreateRequest {
-            client_id: plaid_client.plaid_client_id.clone(),
-            secret: plaid_client.plaid_secret.clone(),
-            client_name: "Plaid Tiny QuickStart - expo RN".to_string(),
-            language: Language::English,
-            country_codes: vec![CountryCode::Us],
-            user: User {
-                client_user_id: id.clone(),
-                ..Default::default()
-            },
-            products: vec![Product::Auth],
-            android_package_name: Some(plaid_client.plaid_android_package_name.clone()),
+    let builder = LinkTokenCreateRequest::builder(
+        "Plaid Tiny QuickStart - expo RN",
+        User {
+            client_user_id: id.clone(),
             ..Default::default()
         },
+        vec![CountryCode::Us],
+        vec![Product::Auth],
+    )
+    .language(Language::English);
+
+    let builder = match (
+        payload.address.as_str(),
+        &state.plaid_redirect_uri,
+        &state.plaid_android_package_name,
+    ) {
+        ("localhost", Some(redirect_uri), _) => builder.redirect_uri(redirect_uri),
+        ("localhost", None, _) => builder,
+        (_, _, Some(android_package_name)) => builder.android_package_name(android_package_name),
+        (_, _, None) => builder,
     };
+    let payload = builder.build()?;
 
-    match plaid_client.link_token_create(payload).await? {
-        Either::Left(success) => {
-            info!("link_token = {}", success.link_token);
+    let success = match plaid_client.link_token_create(payload).await {
+        Ok(success) => success,
+        Err(err) => return plaid_failure(err),
+    };
+    info!("Created link token for client_user_id {}", id);
 
-            // insert an entry for this client in the db
-            // todo : insert only if row not present - refactor flow
-            db::insert_client(&id, &state.database_url)
-                .await
-                .map_err(crate::error::Error::GenericError)?;
+    // insert an entry for this client in the db
+    // todo : insert only if row not present - refactor flow
+    db::insert_client(&id, &state.database_url)
+        .await
+        .map_err(crate::error::Error::GenericError)?;
 
-            Ok(CreateLinkTokenOutput {
-                link_token: Some(success.link_token),
-                failure: None,
-            })
-        }
-        Either::Right(failure) => {
-            info!("plaid error response = {:#?}", failure);
-            Ok(CreateLinkTokenOutput {
-                link_token: None,
-                failure: Some(failure),
-            })
-        }
-    }
+    Ok(CreateLinkTokenOutput {
+        link_token: Some(success.link_token.expose_secret().to_string()),
+        failure: None,
+    })
 }
 
 #[derive(Debug, Deserialize)]
 pub struct ExchangePublicTokenInput {
     client_user_id: String,
-    public_token: String,
+    public_token: PublicToken,
 }
 
 #[derive(Debug, Serialize)]
@@ -120,13 +123,19 @@ pub struct ExchangePublicTokenOutput {
     failure: Option<PlaidErrorResponse>,
 }
 
+impl From<PlaidErrorResponse> for ExchangePublicTokenOutput {
+    fn from(failure: PlaidErrorResponse) -> Self {
+        Self {
+            failure: Some(failure),
+        }
+    }
+}
+
 impl Responder for ExchangePublicTokenOutput {
     type Body = BoxBody;
 
     fn respond_to(self, _req: &actix_web::HttpRequest) -> HttpResponse<Self::Body> {
-        HttpResponse::Ok()
-            .content_type(ContentType::json())
-            .body(serde_json::to_string(&self).unwrap())
+        HttpResponse::Ok().json(self)
     }
 }
 
@@ -139,32 +148,20 @@ pub async fn exchange_public_token(
 
     let id = payload.client_user_id.clone();
     let public_token = payload.public_token.clone();
-    match state
+    let success = match state
         .plaid_client
-        .item_public_token_exchange(ItemPublicTokenExchangeRequest {
-            client_id: state.plaid_client.plaid_client_id.clone(),
-            secret: state.plaid_client.plaid_secret.clone(),
-            public_token: public_token.clone(),
-        })
-        .await?
+        .item_public_token_exchange(ItemPublicTokenExchangeRequest { public_token })
+        .await
     {
-        Either::Left(success) => {
-            debug!("access token = {}", success.access_token);
-
-            // update the db row for this device id with the access token
-            db::update_client(&id, &success.access_token, &state.database_url)
-                .await
-                .map_err(crate::error::Error::GenericError)?;
+        Ok(success) => success,
+        Err(err) => return plaid_failure(err),
+    };
+    // update the db row for this device id with the access token
+    db::update_client(&id, &success.access_token, &state.database_url)
+        .await
+        .map_err(crate::error::Error::GenericError)?;
 
-            Ok(ExchangePublicTokenOutput { failure: None })
-        }
-        Either::Right(failure) => {
-            info!("plaid error response = {:#?}", failure);
-            Ok(ExchangePublicTokenOutput {
-                failure: Some(failure),
-            })
-        }
-    }
+    Ok(ExchangePublicTokenOutput { failure: None })
 }
 
 #[derive(Debug, Deserialize)]
@@ -178,13 +175,20 @@ pub struct GetBalanceOutput {
     failure: Option<PlaidErrorResponse>,
 }
 
+impl From<PlaidErrorResponse> for GetBalanceOutput {
+    fn from(failure: PlaidErrorResponse) -> Self {
+        Self {
+            balance: None,
+            failure: Some(failure),
+        }
+    }
+}
+
 impl Responder for GetBalanceOutput {
     type Body = BoxBody;
 
     fn respond_to(self, _req: &actix_web::HttpRequest) -> HttpResponse<Self::Body> {
-        HttpResponse::Ok()
-            .content_type(ContentType::json())
-            .body(serde_json::to_string(&self).unwrap())
+        HttpResponse::Ok().json(self)
     }
 }
 
@@ -200,30 +204,100 @@ pub async fn balance(
         .await
         .map_err(crate::error::Error::GenericError)?;
 
-    let token = client.access_token.unwrap();
-    match state
+    let token = client
+        .access_token
+        .ok_or_else(|| crate::error::Error::NotLinked(id.clone()))?;
+    let success = match state
         .plaid_client
         .accounts_balance_get(AccountsBalanceGetRequest {
-            client_id: state.plaid_client.plaid_client_id.clone(),
-            secret: state.plaid_client.plaid_secret.clone(),
             access_token: token,
             ..Default::default()
         })
-        .await?
+        .await
     {
-        Either::Left(success) => {
-            info!("balance response = {success:#?}");
-            Ok(GetBalanceOutput {
-                balance: Some(success),
-                failure: None,
-            })
-        }
-        Either::Right(failure) => {
-            info!("plaid error response = {:#?}", failure);
-            Ok(GetBalanceOutput {
-                balance: None,
-                failure: Some(failure),
-            })
-        }
+        Ok(success) => success,
+        Err(err) => return plaid_failure(err),
+    };
+    info!("Retrieved balances for {} accounts", success.accounts.len());
+
+    Ok(GetBalanceOutput {
+        balance: Some(success),
+        failure: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{test, App};
+    use plaid_sdk::{
+        model::error::PlaidErrorType,
+        testing::{plaid_error, FakePlaid},
+    };
+    use serde_json::{json, Value};
+
+    /// The handlers only reach the database after Plaid succeeds, so these
+    /// tests stick to Plaid failures and need no database
+    fn state(plaid: FakePlaid) -> web::Data<Arc<AppState>> {
+        web::Data::new(Arc::new(AppState::new(
+            "postgres://unused".to_string(),
+            Box::new(plaid),
+            None,
+            None,
+        )))
+    }
+
+    #[actix_web::test]
+    async fn plaid_failures_are_reported_in_the_body() {
+        let plaid = FakePlaid::new();
+        plaid.fail_next::<LinkTokenCreateRequest>(plaid_error(
+            PlaidErrorType::RateLimitExceeded,
+            "RATE_LIMIT",
+            "rate limit exceeded",
+        ));
+        let app =
+            test::init_service(App::new().app_data(state(plaid)).service(create_link_token)).await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/create_link_token")
+            .set_json(json!({ "client_user_id": "user-1", "address": "localhost" }))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert!(res.status().is_success());
+
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["link_token"], Value::Null);
+        assert_eq!(body["failure"]["error_code"], "RATE_LIMIT");
+    }
+
+    #[actix_web::test]
+    async fn unlinked_client_is_a_client_error() {
+        let err = crate::error::Error::NotLinked("user-1".into());
+        assert_eq!(
+            actix_web::ResponseError::status_code(&err),
+            actix_web::http::StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn unknown_public_token_is_a_plaid_failure() {
+        let app = test::init_service(
+            App::new()
+                .app_data(state(FakePlaid::new()))
+                .service(exchange_public_token),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/exchange_public_token")
+            .set_json(json!({
+                "client_user_id": "user-1",
+                "public_token": "public-sandbox-unknown",
+            }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(body["failure"]["error_type"], "INVALID_INPUT");
+        assert_eq!(body["failure"]["error_code"], "INVALID_PUBLIC_TOKEN");
     }
 }
@@ -10,29 +10,67 @@ use actix_web::{web, App, HttpServer};
 use clap::Parser;
 use config::Config;
 use handlers::{balance, create_link_token, exchange_public_token};
-use plaid_sdk::PlaidClient;
-use std::sync::Arc;
+use plaid_sdk::{
+    traits::{AccountsApi, ItemsApi, LinkApi},
+    PlaidClient,
+};
+use std::{sync::Arc, time::Duration};
+
+/// The Plaid calls the handlers make, so tests can swap in
+/// `plaid_sdk::testing::FakePlaid` for the real client
+pub trait Plaid: LinkApi + ItemsApi + AccountsApi {}
+
+impl<T: LinkApi + ItemsApi + AccountsApi> Plaid for T {}
 
 pub struct AppState {
     database_url: String,
-    plaid_client: PlaidClient,
+    plaid_client: Box<dyn Plaid>,
+    plaid_redirect_uri: Option<String>,
+    plaid_android_package_name: Option<String>,
+}
+
+impl AppState {
+    pub fn new(
+        database_url: String,
+        plaid_client: Box<dyn Plaid>,
+        plaid_redirect_uri: Option<String>,
+        plaid_android_package_name: Option<String>,
+    ) -> Self {
+        Self {
+            database_url,
+            plaid_client,
+            plaid_redirect_uri,
+            plaid_android_package_name,
+        }
+    }
 }
 
 pub fn create_server() -> eyre::Result<Server> {
     let config = Config::parse();
 
-    let plaid_client = PlaidClient::new(
+    let plaid_env = config
+        .plaid_base_url
+        .unwrap_or_else(|| config.plaid_env.to_string());
+
+    let plaid_client = PlaidClient::builder(
         &config.plaid_client_id,
         &config.plaid_secret,
-        &config.plaid_env.to_string(),
-        &config.plaid_redirect_uri,
-        &config.plaid_android_package_name,
-    );
-
-    let app_data = Arc::new(AppState {
-        database_url: config.database_url.clone(),
-        plaid_client,
-    });
+        plaid_env.as_str().try_into()?,
+    )
+    .redirect_uri(&config.plaid_redirect_uri)
+    .android_package_name(&config.plaid_android_package_name)
+    .plaid_version(&config.plaid_version)
+    .timeout(Duration::from_secs(config.plaid_timeout_secs))
+    .build()?;
+
+    let plaid_redirect_uri = plaid_client.plaid_redirect_uri.clone();
+    let plaid_android_package_name = plaid_client.plaid_android_package_name.clone();
+    let app_data = Arc::new(AppState::new(
+        config.database_url,
+        Box::new(plaid_client),
+        plaid_redirect_uri,
+        plaid_android_package_name,
+    ));
 
     Ok(HttpServer::new(move || {
         App::new()
@@ -1,14 +1,30 @@
 use diesel::{
+    deserialize,
+    pg::Pg,
     prelude::{Insertable, Queryable},
+    sql_types::{Nullable, Text},
     Selectable,
 };
+use plaid_sdk::model::secret::AccessToken;
 
-#[derive(Queryable, Selectable)]
+#[derive(Selectable)]
 #[diesel(table_name = crate::schema::clients)]
-#[diesel(check_for_backend(diesel::pg::Pg))]
 pub struct Client {
     pub client_user_id: String,
-    pub access_token: Option<String>,
+    pub access_token: Option<AccessToken>,
+}
+
+// Implemented by hand because the access token column is stored as plain text
+// but only ever handed out wrapped in `AccessToken`
+impl Queryable<(Text, Nullable<Text>), Pg> for Client {
+    type Row = (String, Option<String>);
+
+    fn build((client_user_id, access_token): Self::Row) -> deserialize::Result<Self> {
+        Ok(Client {
+            client_user_id,
+            access_token: access_token.map(AccessToken::from),
+        })
+    }
 }
 
 #[derive(Insertable)]